@@ -0,0 +1,171 @@
+//! Derive macros for the shared `atcoder_rust` library.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Expr, Field, Fields, GenericArgument, LitInt, LitStr,
+    PathArguments, Type,
+};
+
+/// Derives `atcoder_rust::PuzzleInput` for a struct with named fields, reading the fields in
+/// declaration order from a `Scanner`.
+///
+/// Field attributes:
+///
+/// * `#[line(k)]`: the field starts the 0-based input line `k`, and the following fields without
+///   a `#[line]` attribute must be on the same line. A `Vec` field without `#[line]` is read
+///   from the following tokens, possibly spanning multiple lines.
+/// * `#[len = "n"]`: the item count of a `Vec` field; the expression can refer to earlier fields.
+/// * `#[range(1..=100)]`: the value, or each item of a `Vec`, must be within the range. The
+///   expression can refer to earlier fields, e.g. `#[range(1..h)]` for `1 <= A < H`. Checked in
+///   every build, violations are reported as `PuzzleError::Constraint`, so the solutions can use
+///   the values as indices and in unsigned arithmetic.
+#[proc_macro_derive(PuzzleInput, attributes(line, len, range))]
+pub fn derive_puzzle_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    line: Option<LitInt>,
+    len: Option<Expr>,
+    range: Option<Expr>,
+}
+
+fn parse_attrs(field: &Field) -> syn::Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+    for attr in &field.attrs {
+        if attr.path().is_ident("line") {
+            attrs.line = Some(attr.parse_args::<LitInt>()?);
+        } else if attr.path().is_ident("len") {
+            let value = &attr.meta.require_name_value()?.value;
+            let lit = syn::parse2::<LitStr>(quote!(#value))?;
+            attrs.len = Some(lit.parse::<Expr>()?);
+        } else if attr.path().is_ident("range") {
+            attrs.range = Some(attr.parse_args::<Expr>()?);
+        }
+    }
+    Ok(attrs)
+}
+
+/// Returns `T` if the type is `Vec<T>`.
+fn vec_item_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(item)) if args.args.len() == 1 => Some(item),
+        _ => None,
+    }
+}
+
+fn expand_field(field: &Field) -> syn::Result<TokenStream2> {
+    let ident = field.ident.as_ref().expect("named field");
+    let name = ident.to_string();
+    let ty = &field.ty;
+    let attrs = parse_attrs(field)?;
+    let mut tokens = TokenStream2::new();
+    if let Some(line) = &attrs.line {
+        tokens.extend(quote! { __sc.goto_line(#line, #name)?; });
+    }
+    match (vec_item_type(ty), &attrs.len) {
+        (Some(item), Some(len)) => {
+            if attrs.line.is_none() {
+                tokens.extend(quote! { __sc.unlock_line(); });
+            }
+            tokens.extend(quote! { let #ident: #ty = __sc.read_vec::<#item>(#len, #name)?; });
+            if let Some(range) = &attrs.range {
+                tokens.extend(quote! {
+                    {
+                        let __range = #range;
+                        if let Some(__item) = #ident.iter().find(|__item| !__range.contains(*__item)) {
+                            return Err(::atcoder_rust::PuzzleError::Constraint {
+                                field: #name.to_string(),
+                                value: format!("{:?}", __item),
+                                range: format!("{:?}", __range),
+                            });
+                        }
+                    }
+                });
+            }
+        }
+        (Some(_), None) => {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`Vec` field needs a `#[len = \"...\"]` attribute",
+            ));
+        }
+        (None, Some(_)) => {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`#[len]` is only allowed on `Vec` fields",
+            ));
+        }
+        (None, None) => {
+            tokens.extend(quote! { let #ident: #ty = __sc.read::<#ty>(#name)?; });
+            if let Some(range) = &attrs.range {
+                tokens.extend(quote! {
+                    {
+                        let __range = #range;
+                        if !__range.contains(&#ident) {
+                            return Err(::atcoder_rust::PuzzleError::Constraint {
+                                field: #name.to_string(),
+                                value: format!("{:?}", #ident),
+                                range: format!("{:?}", __range),
+                            });
+                        }
+                    }
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "`PuzzleInput` can be derived only for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`PuzzleInput` can be derived only for structs",
+            ))
+        }
+    };
+    let reads = fields
+        .iter()
+        .map(expand_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let idents = fields.iter().map(|field| &field.ident);
+    Ok(quote! {
+        impl ::atcoder_rust::PuzzleInput for #name {
+            fn read_from<R: ::std::io::BufRead>(
+                __sc: &mut ::atcoder_rust::scanner::Scanner<R>,
+            ) -> ::std::result::Result<Self, ::atcoder_rust::PuzzleError> {
+                #(#reads)*
+                Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
@@ -0,0 +1,38 @@
+//! Generates one `#[test]` per example case `input/PUZZLE_ID_N.txt`, included by the tests of
+//! `src/verify.rs`, so new sample files are covered by `cargo test` without a test function.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const INPUT_DIR: &str = "input";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", INPUT_DIR);
+    let mut stems = fs::read_dir(INPUT_DIR)
+        .expect("cannot read the input directory")
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            Some(file_name.strip_suffix(".txt")?.to_string())
+        })
+        .collect::<Vec<_>>();
+    stems.sort();
+    let mut code = String::new();
+    for stem in stems {
+        let Some((puzzle_id, case)) = stem.rsplit_once('_') else {
+            continue;
+        };
+        let test_name = stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        code += "#[test]\n";
+        code += &format!(
+            "fn case_{}() {{\n    check_case({:?}, {:?});\n}}\n\n",
+            test_name, puzzle_id, case
+        );
+    }
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("sample_tests.rs"), code)
+        .expect("cannot write the generated tests");
+}
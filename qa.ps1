@@ -6,19 +6,11 @@ cargo test
 cargo build --release
 # run
 Write-Host("==============================")
-& "./target/release/atcoder-rust.exe"
+& "./target/release/atcoder-rust.exe" list
 Write-Host("==========")
-$puzzles = @(
-    "practice_a",
-    "abc042_a",
-    "abc042_b",
-    "abc042_c",
-    "abc042_d",
-    "abc043_a",
-    "abc043_b",
-    "abc043_c"
-)
-foreach ($puzzle in $puzzles){
-    & "./target/release/$puzzle.exe"
-}
+& "./target/release/atcoder-rust.exe" run all
+Write-Host("==========")
+& "./target/release/atcoder-rust.exe" verify
+Write-Host("==========")
+& "./target/release/atcoder-rust.exe" judge practice_b
 Write-Host("==========")
@@ -1,51 +1,70 @@
 //! [link](https://atcoder.jp/contests/practice/tasks/practice_2)
 //!
-//! This is an interactive task, it cannot run without a judge.
+//! This is an interactive task, run it with the local judge: `atcoder-rust judge practice_b`
 
+use atcoder_rust::interactive::{Interactor, StdioInteractor};
+use atcoder_rust::PuzzleError;
+use std::cmp::Ordering;
 use std::io;
-use std::io::Write;
+use std::process::ExitCode;
 
 const PUZZLE_ID: &str = "practice_b";
 const TITLE: &str = "practice contest, B - Interactive Sorting";
 
-#[allow(dead_code)]
-#[allow(clippy::enum_variant_names)]
 enum SortAlgorithms {
     BubbleSort,
-    QuickSort, // not working
-    MergeSort, // not working
+    MergeInsertion,
+    FiveItems,
 }
 
-const SORT_ALGO: SortAlgorithms = SortAlgorithms::BubbleSort;
+/// Picks the simplest sort whose worst case fits in `q` queries for `n` items.
+fn select_algorithm(n: usize, q: usize) -> SortAlgorithms {
+    if n * n.saturating_sub(1) <= q {
+        SortAlgorithms::BubbleSort
+    } else if n == 5 {
+        SortAlgorithms::FiveItems
+    } else {
+        SortAlgorithms::MergeInsertion
+    }
+}
 
-fn main() -> Result<(), PuzzleError> {
-    let (n, _q) = read_init_input()?;
-    let mut a = ('A'..='Z').take(n).collect::<Vec<_>>();
-    match SORT_ALGO {
-        SortAlgorithms::BubbleSort => bubble_sort(&mut a)?,
-        SortAlgorithms::QuickSort => quick_sort(&mut a)?,
-        SortAlgorithms::MergeSort => merge_sort(&mut a)?,
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
     }
-    let result = a.iter().collect::<String>();
+}
+
+fn try_main() -> Result<(), PuzzleError> {
+    let mut io = StdioInteractor::new(io::stdin().lock(), io::stdout().lock());
+    let (n, q) = io.read_header()?;
+    let mut a = ('A'..='Z').take(n).collect::<Vec<_>>();
+    sort(select_algorithm(n, q), &mut io, &mut a)?;
     eprintln!("=== {} : {}", PUZZLE_ID, TITLE);
-    println!("{}", result);
-    io::stdout()
-        .flush()
-        .map_err(|_| "error writing to `stdout`")?;
-    Ok(())
+    io.answer(&a)
+}
+
+fn sort(
+    algorithm: SortAlgorithms,
+    io: &mut dyn Interactor,
+    a: &mut [char],
+) -> Result<(), PuzzleError> {
+    match algorithm {
+        SortAlgorithms::BubbleSort => bubble_sort(io, a),
+        SortAlgorithms::MergeInsertion => merge_insertion_sort(io, a),
+        SortAlgorithms::FiveItems => sort_five(io, a),
+    }
 }
 
 /// bubble_sort() is O(n^2), so it passes only test set #1
-fn bubble_sort(a: &mut [char]) -> Result<(), PuzzleError> {
+fn bubble_sort(io: &mut dyn Interactor, a: &mut [char]) -> Result<(), PuzzleError> {
     let n = a.len();
     for _ in 0..n {
-        for j in 0..(n - 1) {
-            println!("? {} {}", a[j], a[j + 1]);
-            io::stdout()
-                .flush()
-                .map_err(|_| "error writing to `stdout`")?;
-            let ans = read_turn_input()?;
-            if ans == '>' {
+        for j in 0..n.saturating_sub(1) {
+            if io.ask(a[j], a[j + 1])? == Ordering::Greater {
                 a.swap(j, j + 1);
             }
         }
@@ -53,128 +72,211 @@ fn bubble_sort(a: &mut [char]) -> Result<(), PuzzleError> {
     Ok(())
 }
 
-/// based on [<https://en.wikipedia.org/wiki/Quicksort>]
-fn quick_sort(a: &mut [char]) -> Result<(), PuzzleError> {
-    let hi = a.len() - 1;
-    qs_iter(a, 0, hi)?;
+/// Ford–Johnson merge-insertion sort, based on Knuth, TAOCP vol. 3, 5.3.1
+///
+/// The worst case is `sum(ceil(log2(3k/4)))` for `k` in `1..=n` queries, 91 for N = 26.
+fn merge_insertion_sort(io: &mut dyn Interactor, a: &mut [char]) -> Result<(), PuzzleError> {
+    let sorted = mi_sorted(io, a)?;
+    a.copy_from_slice(&sorted);
     Ok(())
 }
 
-fn qs_iter(a: &mut [char], lo: usize, hi: usize) -> Result<(), PuzzleError> {
-    if lo >= hi {
-        return Ok(());
+fn mi_sorted(io: &mut dyn Interactor, a: &[char]) -> Result<Vec<char>, PuzzleError> {
+    if a.len() <= 1 {
+        return Ok(a.to_vec());
     }
-    let p = qs_partition(a, lo, hi)?;
-    qs_iter(a, lo, p - 1)?;
-    qs_iter(a, p + 1, hi)?;
-    Ok(())
-}
-
-fn qs_partition(a: &mut [char], lo: usize, hi: usize) -> Result<usize, PuzzleError> {
-    let pivot = a[hi];
-    let mut i = lo;
-    for j in lo..hi {
-        let mut ans = '<';
-        if a[j] != pivot {
-            println!("? {} {}", a[j], pivot);
-            ans = read_turn_input()?;
-            io::stdout()
-                .flush()
-                .map_err(|_| "error writing to `stdout`")?;
+    // (larger, smaller) of each pair, then sort the larger items recursively
+    let mut pairs = Vec::with_capacity(a.len() / 2);
+    for pair in a.chunks_exact(2) {
+        if io.ask(pair[0], pair[1])? == Ordering::Greater {
+            pairs.push((pair[0], pair[1]));
+        } else {
+            pairs.push((pair[1], pair[0]));
         }
-        if ans == '<' {
-            a.swap(i, j);
-            i += 1;
+    }
+    let larger = pairs.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+    let mut chain = mi_sorted(io, &larger)?;
+    // the smaller items in the order of their partners, then the odd one out
+    let mut pending = chain
+        .iter()
+        .map(|x| pairs.iter().find(|p| p.0 == *x).expect("paired").1)
+        .collect::<Vec<_>>();
+    pending.extend(a.chunks_exact(2).remainder());
+    let partners = chain.clone();
+    chain.insert(0, pending[0]);
+    // insert by groups ending at the Jacobsthal numbers 3, 5, 11, 21, ..., each group in reverse,
+    // so that every binary search is over 2^k - 1 items
+    let (mut done, mut power) = (1, 2);
+    while done < pending.len() {
+        power *= 2;
+        let group_end = power - done;
+        for i in (done..group_end.min(pending.len())).rev() {
+            let bound = partners
+                .get(i)
+                .and_then(|partner| chain.iter().position(|x| x == partner))
+                .unwrap_or(chain.len());
+            binary_insert(io, &mut chain, bound, pending[i])?;
         }
+        done = group_end;
     }
-    a.swap(i, hi);
-    Ok(i)
+    Ok(chain)
 }
 
-/// based on [<https://en.wikipedia.org/wiki/Merge_sort>]
-fn merge_sort(a: &mut [char]) -> Result<(), PuzzleError> {
-    let mut b = a.to_vec();
-    let n = a.len();
-    ms_top_down_split_merge(a, 0, n, &mut b)?;
+/// Inserts `x` into the sorted `chain[..bound]`, with `ceil(log2(bound + 1))` queries.
+fn binary_insert(
+    io: &mut dyn Interactor,
+    chain: &mut Vec<char>,
+    bound: usize,
+    x: char,
+) -> Result<(), PuzzleError> {
+    let (mut lo, mut hi) = (0, bound);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if io.ask(x, chain[mid])? == Ordering::Less {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    chain.insert(lo, x);
     Ok(())
 }
 
-fn ms_top_down_split_merge(
-    a: &mut [char],
-    i_begin: usize,
-    i_end: usize,
-    b: &mut [char],
-) -> Result<(), PuzzleError> {
-    if i_end <= i_begin + 1 {
-        return Ok(());
+/// The optimal decision tree for 5 items, 7 queries for test set #3
+fn sort_five(io: &mut dyn Interactor, a: &mut [char]) -> Result<(), PuzzleError> {
+    let [mut p, mut q, mut r, mut s, e] = <[char; 5]>::try_from(&*a).expect("5 items");
+    if io.ask(p, q)? == Ordering::Greater {
+        (p, q) = (q, p);
     }
-    let i_middle = (i_end + i_begin) / 2;
-    ms_top_down_split_merge(a, i_begin, i_middle, b)?;
-    ms_top_down_split_merge(a, i_middle, i_end, b)?;
-    ms_top_down_merge(b, i_begin, i_middle, i_end, a)?;
+    if io.ask(r, s)? == Ordering::Greater {
+        (r, s) = (s, r);
+    }
+    if io.ask(q, s)? == Ordering::Greater {
+        (p, q, r, s) = (r, s, p, q);
+    }
+    // p < q < s and r < s: insert e into 3 items, then r into at most 3 items below s
+    let mut chain = vec![p, q, s];
+    binary_insert(io, &mut chain, 3, e)?;
+    let bound = chain
+        .iter()
+        .position(|&x| x == s)
+        .expect("s is in the chain");
+    binary_insert(io, &mut chain, bound, r)?;
+    a.copy_from_slice(&chain);
     Ok(())
 }
 
-#[allow(clippy::needless_range_loop)]
-fn ms_top_down_merge(
-    a: &mut [char],
-    i_begin: usize,
-    i_middle: usize,
-    i_end: usize,
-    b: &mut [char],
-) -> Result<(), PuzzleError> {
-    let mut i = i_begin;
-    let mut j = i_middle;
-    for k in i_begin..i_end {
-        let mut is_i = true;
-        if i >= i_middle {
-            is_i = false;
-        } else if j < i_end && i != j {
-            println!("? {} {}", a[i], a[j]);
-            io::stdout()
-                .flush()
-                .map_err(|_| "error writing to `stdout`")?;
-            let ans = read_turn_input()?;
-            is_i = ans == '<';
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use atcoder_rust::interactive::{MockInteractor, SortingJudge};
+    use atcoder_rust::rng::XorShift64;
+    use atcoder_rust::verify::Verdict;
+
+    type SortFn = fn(&mut dyn Interactor, &mut [char]) -> Result<(), PuzzleError>;
+
+    /// All the orders of the first `n` letters.
+    fn permutations(n: usize) -> Vec<String> {
+        let mut result = Vec::new();
+        let mut items = ('A'..='Z').take(n).collect::<Vec<_>>();
+        permute(&mut items, 0, &mut result);
+        result
+    }
+
+    fn permute(items: &mut [char], k: usize, result: &mut Vec<String>) {
+        if k == items.len() {
+            result.push(items.iter().collect());
+            return;
         }
-        if is_i {
-            b[k] = a[i];
-            i += 1;
-        } else {
-            b[k] = a[j];
-            j += 1;
+        for i in k..items.len() {
+            items.swap(k, i);
+            permute(items, k + 1, result);
+            items.swap(k, i);
         }
     }
-    Ok(())
-}
 
-type ItemType = i32;
-type PuzzleError = &'static str;
-
-fn read_init_input() -> Result<(usize, ItemType), PuzzleError> {
-    let mut input_line = String::new();
-    io::stdin()
-        .read_line(&mut input_line)
-        .map_err(|_| "error reading input from `stdin`")?;
-    let mut line1_iter = input_line.split_ascii_whitespace();
-    let n = line1_iter
-        .next()
-        .ok_or("missing `n`")?
-        .parse::<usize>()
-        .map_err(|_| "`n` must be integer")?;
-    let q = line1_iter
-        .next()
-        .ok_or("missing `q`")?
-        .parse::<ItemType>()
-        .map_err(|_| "`q` must be integer")?;
-    Ok((n, q))
-}
+    /// Sorts against a hidden `order`, returns the verdict and the query count.
+    fn run_sort(sort: SortFn, order: &str, q: usize) -> (Option<Verdict>, usize) {
+        let mut io = MockInteractor::new(SortingJudge::new(order, q));
+        let mut a = ('A'..='Z').take(order.len()).collect::<Vec<_>>();
+        if sort(&mut io, &mut a).is_ok() {
+            io.answer(&a).unwrap();
+        }
+        (io.verdict(), io.queries())
+    }
+
+    /// The maximum query count over all the permutations of `n` items, checking each sort.
+    fn max_queries(sort: SortFn, n: usize) -> usize {
+        permutations(n)
+            .iter()
+            .map(|order| {
+                let (verdict, queries) = run_sort(sort, order, usize::MAX);
+                assert_eq!(verdict, Some(Verdict::Accepted), "order {}", order);
+                queries
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn permutations_count() {
+        assert_eq!(permutations(4).len(), 24);
+        assert_eq!(permutations(1), vec!["A"]);
+    }
+
+    #[test]
+    fn bubble_sort_all_permutations() {
+        assert_eq!(max_queries(bubble_sort, 1), 0);
+        assert_eq!(max_queries(bubble_sort, 5), 20);
+        assert_eq!(max_queries(bubble_sort, 6), 30);
+    }
+
+    #[test]
+    fn merge_insertion_sort_all_permutations() {
+        // the Ford–Johnson worst case, optimal up to 11 items
+        let expected = [0, 1, 3, 5, 7, 10, 13, 16];
+        for (n, &queries) in (1..).zip(&expected) {
+            assert_eq!(max_queries(merge_insertion_sort, n), queries, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn sort_five_all_permutations() {
+        assert_eq!(max_queries(sort_five, 5), 7);
+    }
+
+    #[test]
+    fn merge_insertion_sort_test_set_2() {
+        let mut rng = XorShift64::new(16);
+        let mut items = ('A'..='Z').collect::<Vec<_>>();
+        for _ in 0..100 {
+            rng.shuffle(&mut items);
+            let order = items.iter().collect::<String>();
+            let (verdict, queries) = run_sort(merge_insertion_sort, &order, 100);
+            assert_eq!(verdict, Some(Verdict::Accepted), "order {}", order);
+            assert!(queries <= 91);
+        }
+    }
 
-fn read_turn_input() -> Result<char, PuzzleError> {
-    let mut input_line = String::new();
-    io::stdin()
-        .read_line(&mut input_line)
-        .map_err(|_| "error reading input from `stdin`")?;
-    let ans = input_line.chars().next().ok_or("missing `ans`")?;
-    Ok(ans)
+    #[test]
+    fn select_algorithm_fits_test_sets() {
+        assert!(matches!(
+            select_algorithm(26, 1000),
+            SortAlgorithms::BubbleSort
+        ));
+        assert!(matches!(
+            select_algorithm(26, 100),
+            SortAlgorithms::MergeInsertion
+        ));
+        assert!(matches!(select_algorithm(5, 7), SortAlgorithms::FiveItems));
+    }
+
+    #[test]
+    fn bubble_sort_over_query_limit() {
+        assert_eq!(
+            run_sort(bubble_sort, "EDCBA", 7),
+            (Some(Verdict::WrongAnswer), 8)
+        );
+    }
 }
@@ -0,0 +1,525 @@
+//! Command line interface of the `atcoder-rust` dispatcher.
+
+use crate::interactive::{self, SortingJudge};
+use crate::rng::XorShift64;
+use crate::{open_file, puzzles, run, verify, DynPuzzle, PuzzleError};
+use std::env;
+use std::io;
+use std::io::IsTerminal;
+
+pub const USAGE: &str = r#"AtCoder solutions in Rust by TBali
+
+Usage:
+    atcoder-rust list                                list the available puzzles
+    atcoder-rust run PUZZLE_ID [--case N | --stdin]  run a puzzle
+    atcoder-rust run all [--case N]                  run all puzzles having example input N
+    atcoder-rust verify [PUZZLE_ID | all]            check all example cases of the puzzles
+    atcoder-rust judge practice_b [--seed S]         run an interactive task with a local judge
+    atcoder-rust help                                show this message
+
+Example input N of a puzzle is read from `./input/PUZZLE_ID_N.txt`, `verify` compares the
+results with the expected outputs in `./input/PUZZLE_ID_N.out`.
+Without `--case` or `--stdin`, the input source is taken from the `ATCODER_INPUT` environment
+variable (`stdin` or a case id). If that is not set either, a piped `stdin` is read,
+otherwise example input 1.
+The peak heap usage is shown next to the running time. In release builds, running times over the
+time limit of the puzzle are flagged as TLE, heap usage over the memory limit as MLE. The times
+are multiplied by the `ATCODER_SAFETY_FACTOR` environment variable (default 1.0) first, use a
+value above 1.0 if the judge is slower than the local machine.
+The interactive `practice_b` is a separate binary, `judge` runs it from the same directory as
+`atcoder-rust`, on each test set of the task, with random hidden data.
+"#;
+
+const DEFAULT_CASE: &str = "1";
+const INPUT_ENV_VAR: &str = "ATCODER_INPUT";
+const SAFETY_FACTOR_ENV_VAR: &str = "ATCODER_SAFETY_FACTOR";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// Decided at runtime, see `InputSource::resolve()`.
+    Auto,
+    Stdin,
+    Case(String),
+}
+
+impl InputSource {
+    /// Replaces `Auto` with the source given by the environment variable, or by auto-detection.
+    ///
+    /// `stdin` is selected only if `allow_stdin` is set and `stdin` is piped, so the submitted
+    /// binary reads `stdin` by default, while a local run from a terminal uses example input 1.
+    pub fn resolve(
+        self,
+        env_value: Option<String>,
+        stdin_is_terminal: bool,
+        allow_stdin: bool,
+    ) -> Self {
+        if self != InputSource::Auto {
+            return self;
+        }
+        match env_value.as_deref().map(str::trim) {
+            Some("stdin") => InputSource::Stdin,
+            Some(case) if !case.is_empty() => InputSource::Case(case.to_string()),
+            _ if allow_stdin && !stdin_is_terminal => InputSource::Stdin,
+            _ => InputSource::Case(DEFAULT_CASE.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Help,
+    List,
+    Run {
+        puzzle_id: String,
+        source: InputSource,
+    },
+    Verify {
+        puzzle_id: String,
+    },
+    Judge {
+        puzzle_id: String,
+        seed: Option<u64>,
+    },
+}
+
+fn usage(msg: &str) -> PuzzleError {
+    PuzzleError::Usage(msg.to_string())
+}
+
+/// Parses the command line arguments (without the program name).
+pub fn parse_args(args: &[String]) -> Result<Command, PuzzleError> {
+    let mut args_iter = args.iter().map(|x| x.as_str());
+    let command = match args_iter.next() {
+        None | Some("help") | Some("--help") | Some("-h") => Command::Help,
+        Some("list") => Command::List,
+        Some("run") => {
+            let puzzle_id = args_iter
+                .next()
+                .ok_or_else(|| usage("missing `PUZZLE_ID`"))?
+                .to_string();
+            let mut source = None;
+            while let Some(arg) = args_iter.next() {
+                if source.is_some() {
+                    Err(usage("only one of `--case` or `--stdin` can be given"))?
+                }
+                source = match arg {
+                    "--stdin" => Some(InputSource::Stdin),
+                    "--case" => Some(InputSource::Case(
+                        args_iter
+                            .next()
+                            .ok_or_else(|| usage("missing case id after `--case`"))?
+                            .to_string(),
+                    )),
+                    _ => Err(usage("unknown option for `run`"))?,
+                };
+            }
+            let source = source.unwrap_or(InputSource::Auto);
+            if puzzle_id == "all" && source == InputSource::Stdin {
+                Err(usage("`--stdin` cannot be used with `run all`"))?
+            }
+            Command::Run { puzzle_id, source }
+        }
+        Some("verify") => Command::Verify {
+            puzzle_id: args_iter.next().unwrap_or("all").to_string(),
+        },
+        Some("judge") => {
+            let puzzle_id = args_iter
+                .next()
+                .ok_or_else(|| usage("missing `PUZZLE_ID`"))?
+                .to_string();
+            let seed = match args_iter.next() {
+                None => None,
+                Some("--seed") => Some(
+                    args_iter
+                        .next()
+                        .and_then(|seed| seed.parse::<u64>().ok())
+                        .ok_or_else(|| usage("`--seed` must be followed by a number"))?,
+                ),
+                Some(_) => Err(usage("unknown option for `judge`"))?,
+            };
+            Command::Judge { puzzle_id, seed }
+        }
+        Some(_) => Err(usage("unknown command"))?,
+    };
+    if args_iter.next().is_some() {
+        Err(usage("too many arguments"))?
+    }
+    Ok(command)
+}
+
+/// Returns the factor for scaling local running times before comparing them with the time limits,
+/// or `None` if the limits are not checked, i.e. in debug builds.
+pub fn safety_factor(
+    env_value: Option<String>,
+    is_release: bool,
+) -> Result<Option<f64>, PuzzleError> {
+    if !is_release {
+        return Ok(None);
+    }
+    match env_value.as_deref().map(str::trim) {
+        None | Some("") => Ok(Some(1.0)),
+        Some(value) => match value.parse::<f64>() {
+            Ok(factor) if factor.is_finite() && factor > 0.0 => Ok(Some(factor)),
+            _ => Err(usage("`ATCODER_SAFETY_FACTOR` must be a positive number")),
+        },
+    }
+}
+
+/// Returns the puzzle with the id, or all puzzles for `all`.
+fn select_puzzles(puzzle_id: &str) -> Result<Vec<&'static dyn DynPuzzle>, PuzzleError> {
+    if puzzle_id == "all" {
+        return Ok(puzzles::PUZZLES.to_vec());
+    }
+    let puzzle = puzzles::find(puzzle_id).ok_or_else(|| usage("unknown puzzle id"))?;
+    Ok(vec![puzzle])
+}
+
+pub fn execute(command: &Command) -> Result<(), PuzzleError> {
+    match command {
+        Command::Help => print!("{}", USAGE),
+        Command::List => {
+            for puzzle in puzzles::PUZZLES {
+                println!("{:<12}{}", puzzle.id(), puzzle.title());
+            }
+        }
+        Command::Run { puzzle_id, source } => {
+            let safety_factor = safety_factor(
+                env::var(SAFETY_FACTOR_ENV_VAR).ok(),
+                !cfg!(debug_assertions),
+            )?;
+            let is_all = puzzle_id == "all";
+            let source = source.clone().resolve(
+                env::var(INPUT_ENV_VAR).ok(),
+                io::stdin().is_terminal(),
+                !is_all,
+            );
+            if is_all && source == InputSource::Stdin {
+                Err(usage("`stdin` cannot be used with `run all`"))?
+            }
+            let mut skipped = 0;
+            let puzzles = select_puzzles(puzzle_id)?;
+            for &puzzle in &puzzles {
+                match &source {
+                    InputSource::Case(case) => {
+                        let mut reader = match open_file(puzzle.id(), case) {
+                            // like `verify all`, run only the puzzles having this case
+                            Err(PuzzleError::Io { source, .. })
+                                if is_all && source.kind() == io::ErrorKind::NotFound =>
+                            {
+                                eprintln!(
+                                    "=== {} : no example input {}, skipped",
+                                    puzzle.id(),
+                                    case
+                                );
+                                skipped += 1;
+                                continue;
+                            }
+                            reader => reader?,
+                        };
+                        run(puzzle, &mut reader, safety_factor)?
+                    }
+                    _ => run(puzzle, &mut io::stdin().lock(), safety_factor)?,
+                }
+            }
+            if skipped == puzzles.len() {
+                Err(usage("no puzzle has this example input"))?
+            }
+        }
+        Command::Verify { puzzle_id } => {
+            let safety_factor = safety_factor(
+                env::var(SAFETY_FACTOR_ENV_VAR).ok(),
+                !cfg!(debug_assertions),
+            )?;
+            if safety_factor.is_none() {
+                eprintln!("note: the time and memory limits are checked only in release builds");
+            }
+            let results = verify::verify(&select_puzzles(puzzle_id)?, safety_factor)?;
+            print!("{}", verify::format_table(&results));
+            let failed = results.iter().filter(|r| r.verdict.is_failure()).count();
+            if failed > 0 {
+                Err(PuzzleError::Judge(format!(
+                    "{} of {} cases failed",
+                    failed,
+                    results.len()
+                )))?
+            }
+        }
+        Command::Judge { puzzle_id, seed } => {
+            if puzzle_id != "practice_b" {
+                Err(usage("unknown interactive puzzle id"))?
+            }
+            let exe = env::current_exe().map_err(|source| PuzzleError::Io {
+                path: "atcoder-rust".to_string(),
+                source,
+            })?;
+            let program = exe.with_file_name(format!("{}{}", puzzle_id, env::consts::EXE_SUFFIX));
+            let mut rng = match seed {
+                Some(seed) => XorShift64::new(*seed),
+                None => XorShift64::from_time(),
+            };
+            let mut failed = 0;
+            for (i, &(n, q)) in interactive::SORTING_TEST_SETS.iter().enumerate() {
+                let mut judge = SortingJudge::random(n, q, &mut rng);
+                let report = interactive::spawn(&program, &mut judge)?;
+                let row = format!(
+                    "{:<12}set {}  N={:<3} Q={:<5}{:<4}{:>5} queries  {}",
+                    puzzle_id,
+                    i + 1,
+                    n,
+                    q,
+                    report.verdict,
+                    report.queries,
+                    report.detail
+                );
+                println!("{}", row.trim_end());
+                if report.verdict.is_failure() {
+                    failed += 1;
+                }
+            }
+            if failed > 0 {
+                Err(PuzzleError::Judge(format!(
+                    "{} of {} test sets failed",
+                    failed,
+                    interactive::SORTING_TEST_SETS.len()
+                )))?
+            }
+        }
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_ascii_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn no_args_is_help() {
+        assert_eq!(parse_args(&[]), Ok(Command::Help));
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(parse_args(&args("list")), Ok(Command::List));
+    }
+
+    #[test]
+    fn run_auto() {
+        let expected = Command::Run {
+            puzzle_id: "abc042_d".to_string(),
+            source: InputSource::Auto,
+        };
+        assert_eq!(parse_args(&args("run abc042_d")), Ok(expected));
+    }
+
+    #[test]
+    fn run_case() {
+        let expected = Command::Run {
+            puzzle_id: "abc042_d".to_string(),
+            source: InputSource::Case("4".to_string()),
+        };
+        assert_eq!(parse_args(&args("run abc042_d --case 4")), Ok(expected));
+    }
+
+    #[test]
+    fn run_stdin() {
+        let expected = Command::Run {
+            puzzle_id: "abc042_d".to_string(),
+            source: InputSource::Stdin,
+        };
+        assert_eq!(parse_args(&args("run abc042_d --stdin")), Ok(expected));
+    }
+
+    #[test]
+    fn verify_all_by_default() {
+        let expected = Command::Verify {
+            puzzle_id: "all".to_string(),
+        };
+        assert_eq!(parse_args(&args("verify")), Ok(expected));
+    }
+
+    #[test]
+    fn verify_puzzle() {
+        let expected = Command::Verify {
+            puzzle_id: "abc042_d".to_string(),
+        };
+        assert_eq!(parse_args(&args("verify abc042_d")), Ok(expected));
+    }
+
+    #[test]
+    fn judge_with_seed() {
+        let expected = Command::Judge {
+            puzzle_id: "practice_b".to_string(),
+            seed: Some(42),
+        };
+        assert_eq!(
+            parse_args(&args("judge practice_b --seed 42")),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn invalid_judge_seed() {
+        assert_eq!(
+            parse_args(&args("judge practice_b --seed x")).unwrap_err(),
+            usage("`--seed` must be followed by a number")
+        );
+    }
+
+    #[test]
+    fn invalid_judge_unknown_puzzle() {
+        let command = parse_args(&args("judge abc042_d")).unwrap();
+        assert_eq!(
+            execute(&command).unwrap_err(),
+            usage("unknown interactive puzzle id")
+        );
+    }
+
+    #[test]
+    fn invalid_missing_puzzle_id() {
+        assert_eq!(
+            parse_args(&args("run")).unwrap_err(),
+            usage("missing `PUZZLE_ID`")
+        );
+    }
+
+    #[test]
+    fn invalid_missing_case() {
+        assert_eq!(
+            parse_args(&args("run abc042_d --case")).unwrap_err(),
+            usage("missing case id after `--case`")
+        );
+    }
+
+    #[test]
+    fn invalid_both_sources() {
+        assert_eq!(
+            parse_args(&args("run abc042_d --case 4 --stdin")).unwrap_err(),
+            usage("only one of `--case` or `--stdin` can be given")
+        );
+    }
+
+    #[test]
+    fn invalid_all_with_stdin() {
+        assert_eq!(
+            parse_args(&args("run all --stdin")).unwrap_err(),
+            usage("`--stdin` cannot be used with `run all`")
+        );
+    }
+
+    #[test]
+    fn invalid_option() {
+        assert_eq!(
+            parse_args(&args("run abc042_d --fast")).unwrap_err(),
+            usage("unknown option for `run`")
+        );
+    }
+
+    #[test]
+    fn invalid_command() {
+        assert_eq!(
+            parse_args(&args("solve")).unwrap_err(),
+            usage("unknown command")
+        );
+    }
+
+    #[test]
+    fn invalid_too_many_arguments() {
+        assert_eq!(
+            parse_args(&args("list abc042_d")).unwrap_err(),
+            usage("too many arguments")
+        );
+    }
+
+    #[test]
+    fn invalid_unknown_puzzle() {
+        let command = parse_args(&args("run abc999_z")).unwrap();
+        assert_eq!(execute(&command).unwrap_err(), usage("unknown puzzle id"));
+    }
+
+    #[test]
+    fn run_all_skips_missing_case() {
+        let command = parse_args(&args("run all --case 4")).unwrap();
+        assert_eq!(execute(&command), Ok(()));
+    }
+
+    #[test]
+    fn invalid_run_all_missing_case() {
+        let command = parse_args(&args("run all --case 9")).unwrap();
+        assert_eq!(
+            execute(&command).unwrap_err(),
+            usage("no puzzle has this example input")
+        );
+    }
+
+    #[test]
+    fn invalid_verify_unknown_puzzle() {
+        let command = parse_args(&args("verify abc999_z")).unwrap();
+        assert_eq!(execute(&command).unwrap_err(), usage("unknown puzzle id"));
+    }
+
+    #[test]
+    fn safety_factor_default() {
+        assert_eq!(safety_factor(None, true), Ok(Some(1.0)));
+        assert_eq!(safety_factor(Some(" ".to_string()), true), Ok(Some(1.0)));
+    }
+
+    #[test]
+    fn safety_factor_from_env() {
+        assert_eq!(safety_factor(Some("2.5".to_string()), true), Ok(Some(2.5)));
+    }
+
+    #[test]
+    fn safety_factor_debug_build() {
+        assert_eq!(safety_factor(Some("2.5".to_string()), false), Ok(None));
+    }
+
+    #[test]
+    fn invalid_safety_factor() {
+        for value in ["x", "0", "-1", "inf"] {
+            let result = safety_factor(Some(value.to_string()), true);
+            assert!(matches!(result, Err(PuzzleError::Usage(_))));
+        }
+    }
+
+    #[test]
+    fn resolve_explicit_source_is_kept() {
+        let source = InputSource::Case("4".to_string());
+        let resolved = source.resolve(Some("stdin".to_string()), false, true);
+        assert_eq!(resolved, InputSource::Case("4".to_string()));
+    }
+
+    #[test]
+    fn resolve_env_stdin() {
+        let resolved = InputSource::Auto.resolve(Some("stdin".to_string()), true, true);
+        assert_eq!(resolved, InputSource::Stdin);
+    }
+
+    #[test]
+    fn resolve_env_case() {
+        let resolved = InputSource::Auto.resolve(Some("4".to_string()), false, true);
+        assert_eq!(resolved, InputSource::Case("4".to_string()));
+    }
+
+    #[test]
+    fn resolve_piped_stdin() {
+        let resolved = InputSource::Auto.resolve(None, false, true);
+        assert_eq!(resolved, InputSource::Stdin);
+    }
+
+    #[test]
+    fn resolve_terminal_uses_default_case() {
+        let resolved = InputSource::Auto.resolve(None, true, true);
+        assert_eq!(resolved, InputSource::Case("1".to_string()));
+    }
+
+    #[test]
+    fn resolve_piped_stdin_not_allowed() {
+        let resolved = InputSource::Auto.resolve(Some("".to_string()), false, false);
+        assert_eq!(resolved, InputSource::Case("1".to_string()));
+    }
+}
@@ -0,0 +1,194 @@
+//! Binomial coefficients and friends modulo a prime, from precomputed factorials.
+//!
+//! `Combination::<M>::new(n)` is O(n), then each query is O(1). The modulus `M` must be a prime
+//! greater than `n`, so that every factorial in the table is invertible.
+
+use crate::modint::ModInt;
+
+/// Factorials and inverse factorials of `0..=n` modulo the prime `M`.
+#[derive(Clone, Debug)]
+pub struct Combination<const M: u32> {
+    fact: Vec<ModInt<M>>,
+    inv_fact: Vec<ModInt<M>>,
+}
+
+impl<const M: u32> Combination<M> {
+    /// Builds the tables up to `n!`.
+    pub fn new(n: usize) -> Self {
+        assert!(
+            (n as u64) < M as u64,
+            "{}! is not invertible modulo {}",
+            n,
+            M
+        );
+        let mut fact = vec![ModInt::one(); n + 1];
+        for i in 1..=n {
+            fact[i] = fact[i - 1] * ModInt::from(i);
+        }
+        // invert n! once, then downwards: 1/(i-1)! = i/i!
+        let mut inv_fact = vec![ModInt::one(); n + 1];
+        inv_fact[n] = fact[n].inv();
+        for i in (1..=n).rev() {
+            inv_fact[i - 1] = inv_fact[i] * ModInt::from(i);
+        }
+        Combination { fact, inv_fact }
+    }
+
+    /// The largest `n` of the tables.
+    pub fn max_n(&self) -> usize {
+        self.fact.len() - 1
+    }
+
+    /// `n!`
+    pub fn fact(&self, n: usize) -> ModInt<M> {
+        self.fact[n]
+    }
+
+    /// `1 / n!`
+    pub fn inv_fact(&self, n: usize) -> ModInt<M> {
+        self.inv_fact[n]
+    }
+
+    /// `1 / n` for `n` in `1..=max_n()`, without a modular exponentiation.
+    pub fn inv(&self, n: usize) -> ModInt<M> {
+        self.inv_fact[n] * self.fact[n - 1]
+    }
+
+    /// `n` choose `k`, zero if `k > n`.
+    pub fn binom(&self, n: usize, k: usize) -> ModInt<M> {
+        if k > n {
+            return ModInt::zero();
+        }
+        self.fact[n] * self.inv_fact[k] * self.inv_fact[n - k]
+    }
+
+    /// `n! / (n - k)!`, the ordered selections of `k` items out of `n`, zero if `k > n`.
+    pub fn perm(&self, n: usize, k: usize) -> ModInt<M> {
+        if k > n {
+            return ModInt::zero();
+        }
+        self.fact[n] * self.inv_fact[n - k]
+    }
+
+    /// Multisets of `k` items out of `n` kinds, `binom(n + k - 1, k)`.
+    pub fn multichoose(&self, n: usize, k: usize) -> ModInt<M> {
+        match (n, k) {
+            (_, 0) => ModInt::one(),
+            (0, _) => ModInt::zero(),
+            _ => self.binom(n + k - 1, k),
+        }
+    }
+
+    /// The `n`-th Catalan number, `binom(2n, n) - binom(2n, n + 1)`, needs `2n <= max_n()`.
+    pub fn catalan(&self, n: usize) -> ModInt<M> {
+        assert!(
+            2 * n <= self.max_n(),
+            "catalan({}) needs the tables up to {}!",
+            n,
+            2 * n
+        );
+        self.binom(2 * n, n) - self.binom(2 * n, n + 1)
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modint::{MODULUS_1E9_7, MODULUS_998};
+
+    /// Pascal's triangle, exact up to `n = 60`.
+    fn pascal(n: usize) -> Vec<Vec<u64>> {
+        let mut rows = vec![vec![1u64]];
+        for i in 1..=n {
+            let prev = &rows[i - 1];
+            let mut row = vec![1; i + 1];
+            for k in 1..i {
+                row[k] = prev[k - 1] + prev[k];
+            }
+            rows.push(row);
+        }
+        rows
+    }
+
+    fn check_binom<const M: u32>(rows: &[Vec<u64>]) {
+        let comb = Combination::<M>::new(rows.len() - 1);
+        for (n, row) in rows.iter().enumerate() {
+            for (k, &value) in row.iter().enumerate() {
+                assert_eq!(comb.binom(n, k), ModInt::new(value), "{} choose {}", n, k);
+            }
+            assert_eq!(comb.binom(n, n + 1), ModInt::zero());
+        }
+    }
+
+    #[test]
+    fn fact_and_inverse() {
+        let comb = Combination::<MODULUS_998>::new(1000);
+        assert_eq!(comb.max_n(), 1000);
+        assert_eq!(comb.fact(5).value(), 120);
+        for n in 0..=1000 {
+            assert_eq!(comb.fact(n) * comb.inv_fact(n), ModInt::one());
+        }
+        for n in 1..=1000 {
+            assert_eq!(comb.inv(n) * ModInt::from(n), ModInt::one());
+        }
+    }
+
+    #[test]
+    fn binom_matches_pascal() {
+        let rows = pascal(60);
+        check_binom::<MODULUS_1E9_7>(&rows);
+        check_binom::<MODULUS_998>(&rows);
+        // small prime, values wrap around
+        check_binom::<101>(&rows);
+    }
+
+    #[test]
+    fn binom_large_mod_p() {
+        let comb = Combination::<MODULUS_1E9_7>::new(200_000);
+        assert_eq!(comb.binom(200_000, 100_000).value(), 879_467_333);
+        assert_eq!(comb.binom(200_000, 0).value(), 1);
+    }
+
+    #[test]
+    fn perm_small() {
+        let comb = Combination::<MODULUS_1E9_7>::new(10);
+        assert_eq!(comb.perm(5, 2).value(), 20);
+        assert_eq!(comb.perm(5, 5).value(), 120);
+        assert_eq!(comb.perm(5, 0).value(), 1);
+        assert_eq!(comb.perm(2, 3).value(), 0);
+    }
+
+    #[test]
+    fn multichoose_small() {
+        let comb = Combination::<MODULUS_998>::new(20);
+        assert_eq!(comb.multichoose(3, 2).value(), 6);
+        assert_eq!(comb.multichoose(1, 5).value(), 1);
+        assert_eq!(comb.multichoose(0, 0).value(), 1);
+        assert_eq!(comb.multichoose(0, 3).value(), 0);
+        assert_eq!(comb.multichoose(5, 0).value(), 1);
+    }
+
+    #[test]
+    fn catalan_sequence() {
+        let comb = Combination::<MODULUS_1E9_7>::new(30);
+        let expected = [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862];
+        for (n, &value) in expected.iter().enumerate() {
+            assert_eq!(comb.catalan(n).value(), value);
+        }
+        assert_eq!(Combination::<MODULUS_998>::new(0).catalan(0).value(), 1);
+        assert_eq!(comb.catalan(15).value(), 9694845);
+    }
+
+    #[test]
+    #[should_panic(expected = "catalan(16) needs the tables up to 32!")]
+    fn invalid_catalan_beyond_table() {
+        Combination::<MODULUS_1E9_7>::new(31).catalan(16);
+    }
+
+    #[test]
+    #[should_panic(expected = "not invertible")]
+    fn invalid_table_not_below_modulus() {
+        Combination::<7>::new(7);
+    }
+}
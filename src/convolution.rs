@@ -0,0 +1,333 @@
+//! Polynomial multiplication by the number-theoretic transform.
+//!
+//! `convolution()` works modulo an NTT-friendly prime like 998244353, where `M - 1` is divisible
+//! by a power of two at least the result length. `convolution_arbitrary_mod()` and
+//! `convolution_i64()` compute the result modulo three such primes and combine them with the
+//! Chinese remainder theorem, so the exact coefficients must be below their product `~ 5.9e25`.
+
+use crate::modint::ModInt;
+
+/// Below this length, the naive O(N·M) product is faster than the transforms.
+const NAIVE_THRESHOLD: usize = 60;
+
+/// The primes of the three-prime CRT, each `c * 2^k + 1` with `k >= 24`.
+const P1: u32 = 754_974_721;
+const P2: u32 = 167_772_161;
+const P3: u32 = 469_762_049;
+
+/// `x^e mod m`, usable in constant evaluation.
+const fn pow_mod(x: u32, mut e: u32, m: u32) -> u32 {
+    let (mut base, mut result) = (x as u64 % m as u64, 1 % m as u64);
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * base % m as u64;
+        }
+        base = base * base % m as u64;
+        e >>= 1;
+    }
+    result as u32
+}
+
+/// The smallest primitive root of the prime `m`, found by testing `g^((m - 1) / q) != 1` for
+/// every prime factor `q` of `m - 1`.
+const fn smallest_primitive_root(m: u32) -> u32 {
+    // at most 9 distinct prime factors below 2^32
+    let mut factors = [0; 9];
+    let mut count = 0;
+    let mut rest = m - 1;
+    let mut p = 2;
+    while p as u64 * p as u64 <= rest as u64 {
+        if rest % p == 0 {
+            factors[count] = p;
+            count += 1;
+            while rest % p == 0 {
+                rest /= p;
+            }
+        }
+        p += 1;
+    }
+    if rest > 1 {
+        factors[count] = rest;
+        count += 1;
+    }
+    let mut g = 2;
+    while g < m {
+        let mut i = 0;
+        while i < count && pow_mod(g, (m - 1) / factors[i], m) != 1 {
+            i += 1;
+        }
+        if i == count {
+            return g;
+        }
+        g += 1;
+    }
+    1
+}
+
+/// Evaluates the primitive root once per modulus, at compile time.
+struct PrimitiveRoot<const M: u32>;
+
+impl<const M: u32> PrimitiveRoot<M> {
+    const VALUE: u32 = smallest_primitive_root(M);
+}
+
+/// The smallest primitive root of the prime `M`.
+pub fn primitive_root<const M: u32>() -> ModInt<M> {
+    ModInt::from(PrimitiveRoot::<M>::VALUE)
+}
+
+/// In-place NTT of a power-of-two length, the inverse transform includes the division by `n`.
+fn ntt<const M: u32>(a: &mut [ModInt<M>], invert: bool) {
+    let n = a.len();
+    assert!(
+        n.is_power_of_two() && (M as usize - 1) % n == 0,
+        "no NTT of length {} modulo {}",
+        n,
+        M
+    );
+    // bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let root = primitive_root::<M>();
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = root.pow((M as u64 - 1) / len as u64);
+        if invert {
+            w_len = w_len.inv();
+        }
+        for chunk in a.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut w = ModInt::one();
+            for (x, y) in lo.iter_mut().zip(hi) {
+                let (u, v) = (*x, *y * w);
+                *x = u + v;
+                *y = u - v;
+                w *= w_len;
+            }
+        }
+        len <<= 1;
+    }
+    if invert {
+        let n_inv = ModInt::from(n).inv();
+        for x in a {
+            *x *= n_inv;
+        }
+    }
+}
+
+fn convolution_naive<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    let mut result = vec![ModInt::zero(); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            result[i + j] += x * y;
+        }
+    }
+    result
+}
+
+/// The coefficients of the product of two polynomials modulo the NTT-friendly prime `M`,
+/// empty if either is empty.
+pub fn convolution<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) <= NAIVE_THRESHOLD {
+        return convolution_naive(a, b);
+    }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(size, ModInt::zero());
+    fb.resize(size, ModInt::zero());
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x *= *y;
+    }
+    ntt(&mut fa, true);
+    fa.truncate(len);
+    fa
+}
+
+/// Multiplies coefficients given as `u128` residues, modulo the three CRT primes.
+fn crt_product(a: &[u128], b: &[u128]) -> Vec<u128> {
+    fn reduce<const M: u32>(a: &[u128]) -> Vec<ModInt<M>> {
+        a.iter().map(|&x| ModInt::from(x)).collect()
+    }
+    let r1 = convolution(&reduce::<P1>(a), &reduce::<P1>(b));
+    let r2 = convolution(&reduce::<P2>(a), &reduce::<P2>(b));
+    let r3 = convolution(&reduce::<P3>(a), &reduce::<P3>(b));
+    // Garner: x = x1 + P1 * t1 + P1 * P2 * t2
+    let p1_inv = ModInt::<P2>::from(P1).inv();
+    let p12_inv = ModInt::<P3>::from(P1 as u64 * P2 as u64).inv();
+    r1.iter()
+        .zip(&r2)
+        .zip(&r3)
+        .map(|((&x1, &x2), &x3)| {
+            let t1 = (x2 - ModInt::from(x1.value())) * p1_inv;
+            let x12 = x1.value() as u64 + P1 as u64 * t1.value() as u64;
+            let t2 = (x3 - ModInt::from(x12)) * p12_inv;
+            x12 as u128 + (P1 as u128 * P2 as u128) * t2.value() as u128
+        })
+        .collect()
+}
+
+/// `convolution()` modulo any `M`, not only NTT-friendly primes.
+pub fn convolution_arbitrary_mod<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    let to_u128 = |v: &[ModInt<M>]| v.iter().map(|x| x.value() as u128).collect::<Vec<_>>();
+    crt_product(&to_u128(a), &to_u128(b))
+        .into_iter()
+        .map(ModInt::from)
+        .collect()
+}
+
+/// The exact product of integer polynomials, the coefficients must fit in `i64`.
+pub fn convolution_i64(a: &[i64], b: &[i64]) -> Vec<i64> {
+    const P: u128 = P1 as u128 * P2 as u128 * P3 as u128;
+    // residues in 0..P, the upper half stands for the negative values
+    let to_u128 = |v: &[i64]| {
+        v.iter()
+            .map(|&x| (x as i128).rem_euclid(P as i128) as u128)
+            .collect::<Vec<_>>()
+    };
+    crt_product(&to_u128(a), &to_u128(b))
+        .into_iter()
+        .map(|x| {
+            if x > P / 2 {
+                (x as i128 - P as i128) as i64
+            } else {
+                x as i64
+            }
+        })
+        .collect()
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modint::{Mint1e9_7, Mint998, MODULUS_998};
+    use crate::rng::XorShift64;
+
+    fn random_vec<const M: u32>(rng: &mut XorShift64, len: usize) -> Vec<ModInt<M>> {
+        (0..len).map(|_| ModInt::new(rng.next_u64())).collect()
+    }
+
+    fn naive_i64(a: &[i64], b: &[i64]) -> Vec<i64> {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let mut result = vec![0; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                result[i + j] += x * y;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn primitive_roots() {
+        assert_eq!(primitive_root::<MODULUS_998>().value(), 3);
+        assert_eq!(primitive_root::<1_000_000_007>().value(), 5);
+        assert_eq!(primitive_root::<P1>().value(), 11);
+        assert_eq!(primitive_root::<P2>().value(), 3);
+        assert_eq!(primitive_root::<P3>().value(), 3);
+        assert_eq!(primitive_root::<7>().value(), 3);
+        assert_eq!(primitive_root::<4_294_967_291>().value(), 2);
+    }
+
+    #[test]
+    fn ntt_round_trip() {
+        let mut rng = XorShift64::new(19);
+        let a = random_vec::<MODULUS_998>(&mut rng, 256);
+        let mut b = a.clone();
+        ntt(&mut b, false);
+        assert_ne!(a, b);
+        ntt(&mut b, true);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn convolution_small() {
+        let a = [1, 2, 3].map(Mint998::new);
+        let b = [4, 5].map(Mint998::new);
+        let expected = [4, 13, 22, 15].map(Mint998::new);
+        assert_eq!(convolution(&a, &b), expected);
+        assert_eq!(convolution(&a, &[]), []);
+    }
+
+    #[test]
+    fn convolution_random_vs_naive() {
+        let mut rng = XorShift64::new(1);
+        for (n, m) in [
+            (1, 1),
+            (61, 61),
+            (100, 200),
+            (300, 61),
+            (512, 513),
+            (1000, 1),
+        ] {
+            let a = random_vec::<MODULUS_998>(&mut rng, n);
+            let b = random_vec::<MODULUS_998>(&mut rng, m);
+            assert_eq!(
+                convolution(&a, &b),
+                convolution_naive(&a, &b),
+                "{} x {}",
+                n,
+                m
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no NTT")]
+    fn invalid_convolution_not_ntt_friendly() {
+        let a = vec![Mint1e9_7::one(); 100];
+        convolution(&a, &a);
+    }
+
+    #[test]
+    fn convolution_arbitrary_mod_random_vs_naive() {
+        let mut rng = XorShift64::new(2);
+        for (n, m) in [(1, 5), (70, 80), (257, 300)] {
+            let a = random_vec::<1_000_000_007>(&mut rng, n);
+            let b = random_vec::<1_000_000_007>(&mut rng, m);
+            assert_eq!(convolution_arbitrary_mod(&a, &b), convolution_naive(&a, &b));
+        }
+        // largest residues, where the exact sums are close to the CRT range
+        let a = vec![ModInt::<{ u32::MAX }>::new(u32::MAX as u64 - 1); 1000];
+        assert_eq!(convolution_arbitrary_mod(&a, &a), convolution_naive(&a, &a));
+    }
+
+    #[test]
+    fn convolution_i64_random_vs_naive() {
+        let mut rng = XorShift64::new(3);
+        for (n, m) in [(0, 5), (3, 4), (100, 100), (500, 70)] {
+            let a = (0..n)
+                .map(|_| rng.range_i64(-1_000_000, 1_000_000))
+                .collect::<Vec<_>>();
+            let b = (0..m)
+                .map(|_| rng.range_i64(-1_000_000, 1_000_000))
+                .collect::<Vec<_>>();
+            assert_eq!(convolution_i64(&a, &b), naive_i64(&a, &b), "{} x {}", n, m);
+        }
+        // results near the ends of the i64 range
+        let big = i64::MAX / 4;
+        assert_eq!(
+            convolution_i64(&[big, -big], &[2, 2]),
+            [2 * big, 0, -2 * big]
+        );
+    }
+}
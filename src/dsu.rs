@@ -0,0 +1,434 @@
+//! Disjoint-set union (union-find) over the items `0..n`.
+//!
+//! `Dsu` uses path compression and union by size, amortized O(α(n)) per operation.
+//! `WeightedDsu` also keeps a potential per item, for constraints `p(b) - p(a) = w`.
+//! `RollbackDsu` skips path compression, so merges can be undone in O(1), O(log n) per query.
+//!
+//! ```
+//! use atcoder_rust::dsu::Dsu;
+//!
+//! let mut dsu = Dsu::new(4);
+//! dsu.merge(0, 1);
+//! dsu.merge(3, 1);
+//! assert!(dsu.same(0, 3));
+//! assert_eq!(dsu.groups(), [vec![0, 1, 3], vec![2]]);
+//! ```
+
+use std::ops::{Add, Neg, Sub};
+
+/// Union-find with path compression and union by size.
+#[derive(Clone, Debug)]
+pub struct Dsu {
+    /// The parent of a child, or `-size` for a leader.
+    parent_or_size: Vec<isize>,
+}
+
+impl Dsu {
+    pub fn new(n: usize) -> Self {
+        Dsu {
+            parent_or_size: vec![-1; n],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent_or_size.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent_or_size.is_empty()
+    }
+
+    /// Joins the sets of `a` and `b`, returns the leader of the joined set.
+    pub fn merge(&mut self, a: usize, b: usize) -> usize {
+        let (mut x, mut y) = (self.leader(a), self.leader(b));
+        if x == y {
+            return x;
+        }
+        if self.parent_or_size[x] > self.parent_or_size[y] {
+            (x, y) = (y, x);
+        }
+        self.parent_or_size[x] += self.parent_or_size[y];
+        self.parent_or_size[y] = x as isize;
+        x
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.leader(a) == self.leader(b)
+    }
+
+    /// The representative of the set of `a`.
+    pub fn leader(&mut self, a: usize) -> usize {
+        let mut root = a;
+        while self.parent_or_size[root] >= 0 {
+            root = self.parent_or_size[root] as usize;
+        }
+        // path compression, iterative to avoid deep recursion
+        let mut x = a;
+        while self.parent_or_size[x] >= 0 {
+            let next = self.parent_or_size[x] as usize;
+            self.parent_or_size[x] = root as isize;
+            x = next;
+        }
+        root
+    }
+
+    /// The size of the set of `a`.
+    pub fn size(&mut self, a: usize) -> usize {
+        let x = self.leader(a);
+        -self.parent_or_size[x] as usize
+    }
+
+    /// The sets, each in increasing order, ordered by their smallest item.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let n = self.len();
+        let mut index_of_leader = vec![usize::MAX; n];
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for a in 0..n {
+            let x = self.leader(a);
+            if index_of_leader[x] == usize::MAX {
+                index_of_leader[x] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[index_of_leader[x]].push(a);
+        }
+        groups
+    }
+}
+
+/// Union-find with a potential per item, relative to its leader, for difference constraints.
+///
+/// `T` is any additive group, like `i64` or `ModInt<M>`.
+#[derive(Clone, Debug)]
+pub struct WeightedDsu<T> {
+    parent_or_size: Vec<isize>,
+    /// `p(a) - p(parent(a))`, zero for a leader.
+    diff_to_parent: Vec<T>,
+}
+
+impl<T> WeightedDsu<T>
+where
+    T: Copy + Default + PartialEq + Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    pub fn new(n: usize) -> Self {
+        WeightedDsu {
+            parent_or_size: vec![-1; n],
+            diff_to_parent: vec![T::default(); n],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent_or_size.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent_or_size.is_empty()
+    }
+
+    /// The representative of the set of `a`, compressing the path and the potentials.
+    pub fn leader(&mut self, a: usize) -> usize {
+        let mut path = Vec::new();
+        let mut root = a;
+        while self.parent_or_size[root] >= 0 {
+            path.push(root);
+            root = self.parent_or_size[root] as usize;
+        }
+        // from the top of the path, so each parent is already relative to the root
+        for &x in path.iter().rev() {
+            let parent = self.parent_or_size[x] as usize;
+            if parent != root {
+                self.diff_to_parent[x] = self.diff_to_parent[x] + self.diff_to_parent[parent];
+                self.parent_or_size[x] = root as isize;
+            }
+        }
+        root
+    }
+
+    /// `p(a) - p(leader(a))`
+    pub fn potential(&mut self, a: usize) -> T {
+        self.leader(a);
+        self.diff_to_parent[a]
+    }
+
+    /// `p(b) - p(a)`, or `None` if they are not in the same set.
+    pub fn diff(&mut self, a: usize, b: usize) -> Option<T> {
+        (self.leader(a) == self.leader(b)).then(|| self.potential(b) - self.potential(a))
+    }
+
+    /// Adds the constraint `p(b) - p(a) = w`, joining the sets of `a` and `b`.
+    ///
+    /// Returns `false` if it contradicts the earlier constraints, then nothing changes.
+    pub fn merge(&mut self, a: usize, b: usize, w: T) -> bool {
+        let (x, y) = (self.leader(a), self.leader(b));
+        if x == y {
+            return self.potential(b) - self.potential(a) == w;
+        }
+        // p(y) - p(x) from p(b) - p(a) = w
+        let mut w = w + self.potential(a) - self.potential(b);
+        let (mut x, mut y) = (x, y);
+        if self.parent_or_size[x] > self.parent_or_size[y] {
+            (x, y) = (y, x);
+            w = -w;
+        }
+        self.parent_or_size[x] += self.parent_or_size[y];
+        self.parent_or_size[y] = x as isize;
+        self.diff_to_parent[y] = w;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.leader(a) == self.leader(b)
+    }
+
+    pub fn size(&mut self, a: usize) -> usize {
+        let x = self.leader(a);
+        -self.parent_or_size[x] as usize
+    }
+}
+
+/// Union-find with undo, for offline dynamic connectivity and divide and conquer.
+#[derive(Clone, Debug)]
+pub struct RollbackDsu {
+    parent_or_size: Vec<isize>,
+    /// The `(leader, child, child size)` of each effective merge, `None` for a no-op merge.
+    history: Vec<Option<(usize, usize, isize)>>,
+}
+
+impl RollbackDsu {
+    pub fn new(n: usize) -> Self {
+        RollbackDsu {
+            parent_or_size: vec![-1; n],
+            history: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent_or_size.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent_or_size.is_empty()
+    }
+
+    /// The representative of the set of `a`, in O(log n) without path compression.
+    pub fn leader(&self, a: usize) -> usize {
+        let mut x = a;
+        while self.parent_or_size[x] >= 0 {
+            x = self.parent_or_size[x] as usize;
+        }
+        x
+    }
+
+    pub fn same(&self, a: usize, b: usize) -> bool {
+        self.leader(a) == self.leader(b)
+    }
+
+    pub fn size(&self, a: usize) -> usize {
+        -self.parent_or_size[self.leader(a)] as usize
+    }
+
+    /// Joins the sets of `a` and `b`, returns `false` if they were already joined. Either way,
+    /// the merge is recorded and can be undone.
+    pub fn merge(&mut self, a: usize, b: usize) -> bool {
+        let (mut x, mut y) = (self.leader(a), self.leader(b));
+        if x == y {
+            self.history.push(None);
+            return false;
+        }
+        if self.parent_or_size[x] > self.parent_or_size[y] {
+            (x, y) = (y, x);
+        }
+        self.history.push(Some((x, y, self.parent_or_size[y])));
+        self.parent_or_size[x] += self.parent_or_size[y];
+        self.parent_or_size[y] = x as isize;
+        true
+    }
+
+    /// The number of recorded merges, to `rollback()` to.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes the last merge, returns `false` if there is none.
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(Some((x, y, size_y))) => {
+                self.parent_or_size[y] = size_y;
+                self.parent_or_size[x] -= size_y;
+                true
+            }
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    /// Undoes the merges after `snapshot`.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modint::ModInt;
+    use crate::rng::XorShift64;
+
+    /// Component labels by relabeling on each merge, O(n) per merge.
+    struct NaiveDsu {
+        label: Vec<usize>,
+    }
+
+    impl NaiveDsu {
+        fn new(n: usize) -> Self {
+            NaiveDsu {
+                label: (0..n).collect(),
+            }
+        }
+
+        fn merge(&mut self, a: usize, b: usize) {
+            let (from, to) = (self.label[b], self.label[a]);
+            self.label
+                .iter_mut()
+                .filter(|l| **l == from)
+                .for_each(|l| *l = to);
+        }
+
+        fn same(&self, a: usize, b: usize) -> bool {
+            self.label[a] == self.label[b]
+        }
+
+        fn size(&self, a: usize) -> usize {
+            self.label.iter().filter(|&&l| l == self.label[a]).count()
+        }
+    }
+
+    #[test]
+    fn dsu_vs_naive() {
+        let mut rng = XorShift64::new(22);
+        let n = 50;
+        let mut dsu = Dsu::new(n);
+        let mut naive = NaiveDsu::new(n);
+        for _ in 0..200 {
+            let (a, b) = (rng.below(n as u64) as usize, rng.below(n as u64) as usize);
+            if rng.below(2) == 0 {
+                let leader = dsu.merge(a, b);
+                naive.merge(a, b);
+                assert_eq!(dsu.leader(a), leader);
+            }
+            assert_eq!(dsu.same(a, b), naive.same(a, b));
+            assert_eq!(dsu.size(a), naive.size(a));
+        }
+        let groups = dsu.groups();
+        assert_eq!(groups.iter().map(Vec::len).sum::<usize>(), n);
+        for group in &groups {
+            assert!(group
+                .windows(2)
+                .all(|w| w[0] < w[1] && naive.same(w[0], w[1])));
+        }
+    }
+
+    #[test]
+    fn dsu_long_chain() {
+        let n = 1_000_000;
+        let mut dsu = Dsu::new(n);
+        for i in 1..n {
+            dsu.merge(i - 1, i);
+        }
+        assert_eq!(dsu.size(0), n);
+        assert_eq!(dsu.groups().len(), 1);
+        assert!(Dsu::new(0).is_empty());
+    }
+
+    #[test]
+    fn weighted_dsu_vs_hidden_potentials() {
+        let mut rng = XorShift64::new(23);
+        let n = 40;
+        let hidden = (0..n)
+            .map(|_| rng.range_i64(-1000, 1000))
+            .collect::<Vec<_>>();
+        let mut dsu = WeightedDsu::<i64>::new(n);
+        let mut naive = NaiveDsu::new(n);
+        for _ in 0..300 {
+            let (a, b) = (rng.below(n as u64) as usize, rng.below(n as u64) as usize);
+            let w = hidden[b] - hidden[a];
+            assert!(dsu.merge(a, b, w));
+            naive.merge(a, b);
+            let (c, d) = (rng.below(n as u64) as usize, rng.below(n as u64) as usize);
+            let expected = naive.same(c, d).then(|| hidden[d] - hidden[c]);
+            assert_eq!(dsu.diff(c, d), expected);
+            assert_eq!(dsu.size(c), naive.size(c));
+        }
+    }
+
+    #[test]
+    fn weighted_dsu_contradiction() {
+        let mut dsu = WeightedDsu::<i64>::new(3);
+        assert!(dsu.merge(0, 1, 5));
+        assert!(dsu.merge(1, 2, -2));
+        assert_eq!(dsu.diff(0, 2), Some(3));
+        assert_eq!(dsu.diff(2, 0), Some(-3));
+        assert!(!dsu.merge(0, 2, 4));
+        assert!(dsu.merge(2, 0, -3));
+        assert_eq!(dsu.diff(0, 2), Some(3));
+        assert!(dsu.same(0, 2));
+    }
+
+    #[test]
+    fn weighted_dsu_modint() {
+        type Mint7 = ModInt<7>;
+        let mut dsu = WeightedDsu::<Mint7>::new(3);
+        assert!(dsu.merge(0, 1, Mint7::new(5)));
+        assert!(dsu.merge(1, 2, Mint7::new(4)));
+        assert_eq!(dsu.diff(0, 2), Some(Mint7::new(2)));
+        let leader = dsu.leader(1);
+        assert_eq!(dsu.potential(leader), Mint7::zero());
+    }
+
+    #[test]
+    fn rollback_dsu_vs_recomputation() {
+        let mut rng = XorShift64::new(24);
+        let n = 30;
+        let mut dsu = RollbackDsu::new(n);
+        let mut merges: Vec<(usize, usize)> = Vec::new();
+        for _ in 0..500 {
+            match rng.below(3) {
+                0 if !merges.is_empty() => {
+                    let keep = rng.below(merges.len() as u64 + 1) as usize;
+                    dsu.rollback(keep);
+                    merges.truncate(keep);
+                }
+                _ => {
+                    let (a, b) = (rng.below(n as u64) as usize, rng.below(n as u64) as usize);
+                    dsu.merge(a, b);
+                    merges.push((a, b));
+                }
+            }
+            assert_eq!(dsu.snapshot(), merges.len());
+            let mut naive = NaiveDsu::new(n);
+            for &(a, b) in &merges {
+                naive.merge(a, b);
+            }
+            for a in 0..n {
+                let b = (a * 7 + 3) % n;
+                assert_eq!(dsu.same(a, b), naive.same(a, b));
+                assert_eq!(dsu.size(a), naive.size(a));
+            }
+        }
+    }
+
+    #[test]
+    fn rollback_dsu_undo() {
+        let mut dsu = RollbackDsu::new(3);
+        assert!(dsu.merge(0, 1));
+        assert!(!dsu.merge(1, 0));
+        assert!(dsu.undo());
+        assert!(dsu.same(0, 1));
+        assert!(dsu.undo());
+        assert!(!dsu.same(0, 1));
+        assert!(!dsu.undo());
+        assert_eq!(dsu.size(0), 1);
+    }
+}
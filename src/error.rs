@@ -0,0 +1,209 @@
+//! Error type shared by the harness, the scanner and the puzzle solutions.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum PuzzleError {
+    /// Reading or writing failed; `path` is the file name, or `stdin` / `stdout` / `input`.
+    Io { path: String, source: io::Error },
+    /// A token of the input is missing (`token` is `None`) or cannot be parsed.
+    ///
+    /// `line` is 1-based. `field` is empty if no more input was expected.
+    Parse {
+        line: usize,
+        token: Option<String>,
+        field: String,
+        expected: String,
+    },
+    /// A parsed value violates a constraint of the problem statement.
+    Constraint {
+        field: String,
+        value: String,
+        range: String,
+    },
+    /// Invalid response from, or verdict of a judge.
+    Judge(String),
+    /// Invalid command line arguments.
+    Usage(String),
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzleError::Io { path, source } => write!(f, "I/O error on `{}`: {}", path, source),
+            PuzzleError::Parse {
+                line,
+                token: None,
+                field,
+                ..
+            } => write!(f, "line {}: missing `{}`", line, field),
+            PuzzleError::Parse {
+                line,
+                token: Some(token),
+                field,
+                expected,
+            } => {
+                if field.is_empty() {
+                    write!(f, "line {}: expected {}, found `{}`", line, expected, token)
+                } else {
+                    write!(
+                        f,
+                        "line {}: `{}` must be {}, found `{}`",
+                        line, field, expected, token
+                    )
+                }
+            }
+            PuzzleError::Constraint {
+                field,
+                value,
+                range,
+            } => write!(
+                f,
+                "`{}` = {} violates the constraint {}",
+                field, value, range
+            ),
+            PuzzleError::Judge(msg) => write!(f, "judge error: {}", msg),
+            PuzzleError::Usage(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// I/O errors are compared by path and kind only.
+impl PartialEq for PuzzleError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                PuzzleError::Io { path, source },
+                PuzzleError::Io {
+                    path: other_path,
+                    source: other_source,
+                },
+            ) => path == other_path && source.kind() == other_source.kind(),
+            (
+                PuzzleError::Parse {
+                    line,
+                    token,
+                    field,
+                    expected,
+                },
+                PuzzleError::Parse {
+                    line: other_line,
+                    token: other_token,
+                    field: other_field,
+                    expected: other_expected,
+                },
+            ) => {
+                line == other_line
+                    && token == other_token
+                    && field == other_field
+                    && expected == other_expected
+            }
+            (
+                PuzzleError::Constraint {
+                    field,
+                    value,
+                    range,
+                },
+                PuzzleError::Constraint {
+                    field: other_field,
+                    value: other_value,
+                    range: other_range,
+                },
+            ) => field == other_field && value == other_value && range == other_range,
+            (PuzzleError::Judge(msg), PuzzleError::Judge(other_msg)) => msg == other_msg,
+            (PuzzleError::Usage(msg), PuzzleError::Usage(other_msg)) => msg == other_msg,
+            _ => false,
+        }
+    }
+}
+
+impl error::Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            PuzzleError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn display_io() {
+        let err = PuzzleError::Io {
+            path: "./input/abc042_d_0.txt".to_string(),
+            source: io::Error::new(io::ErrorKind::NotFound, "not found"),
+        };
+        assert_eq!(
+            err.to_string(),
+            "I/O error on `./input/abc042_d_0.txt`: not found"
+        );
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn eq_io_by_kind() {
+        let err = |kind| PuzzleError::Io {
+            path: "input".to_string(),
+            source: io::Error::from(kind),
+        };
+        assert_eq!(err(io::ErrorKind::NotFound), err(io::ErrorKind::NotFound));
+        assert_ne!(err(io::ErrorKind::NotFound), err(io::ErrorKind::Other));
+    }
+
+    #[test]
+    fn display_parse_missing() {
+        let err = PuzzleError::Parse {
+            line: 2,
+            token: None,
+            field: "b".to_string(),
+            expected: "integer".to_string(),
+        };
+        assert_eq!(err.to_string(), "line 2: missing `b`");
+    }
+
+    #[test]
+    fn display_parse_invalid() {
+        let err = PuzzleError::Parse {
+            line: 1,
+            token: Some("x".to_string()),
+            field: "h".to_string(),
+            expected: "positive integer".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "line 1: `h` must be positive integer, found `x`"
+        );
+    }
+
+    #[test]
+    fn display_parse_trailing() {
+        let err = PuzzleError::Parse {
+            line: 3,
+            token: Some("1".to_string()),
+            field: String::new(),
+            expected: "end of input".to_string(),
+        };
+        assert_eq!(err.to_string(), "line 3: expected end of input, found `1`");
+    }
+
+    #[test]
+    fn display_constraint() {
+        let err = PuzzleError::Constraint {
+            field: "h".to_string(),
+            value: "0".to_string(),
+            range: "1..=100000".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "`h` = 0 violates the constraint 1..=100000"
+        );
+        assert!(err.source().is_none());
+    }
+}
@@ -0,0 +1,434 @@
+//! Fenwick trees (binary indexed trees) over the items `0..n`, O(log n) per operation.
+//!
+//! `FenwickTree` adds to single items and sums ranges, `RangeFenwickTree` also adds to whole
+//! ranges, and `FenwickTree2D` sums rectangles of an `h × w` grid. Ranges are half-open.
+//!
+//! ```
+//! use atcoder_rust::fenwick::FenwickTree;
+//!
+//! let mut tree = FenwickTree::from(vec![1i64, 0, 2, 5]);
+//! tree.add(1, 3);
+//! assert_eq!(tree.sum(1..3), 5);
+//! assert_eq!(tree.lower_bound(4), 1);
+//! ```
+
+use crate::range::to_half_open;
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, RangeBounds, Sub};
+
+/// Point additions and range sums, for any `T` whose `Default` is zero.
+#[derive(Clone, Debug)]
+pub struct FenwickTree<T> {
+    /// `data[i - 1]` is the sum of the `i & -i` items ending at `i - 1`.
+    data: Vec<T>,
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// `n` zeros.
+    pub fn new(n: usize) -> Self {
+        FenwickTree {
+            data: vec![T::default(); n],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn add(&mut self, p: usize, x: T) {
+        let n = self.len();
+        assert!(p < n, "index {} out of 0..{}", p, n);
+        let mut i = p + 1;
+        while i <= n {
+            self.data[i - 1] = self.data[i - 1] + x;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// The sum of the first `r` items.
+    pub fn prefix_sum(&self, r: usize) -> T {
+        assert!(r <= self.len(), "index {} out of 0..={}", r, self.len());
+        let mut sum = T::default();
+        let mut i = r;
+        while i > 0 {
+            sum = sum + self.data[i - 1];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    pub fn sum(&self, range: impl RangeBounds<usize>) -> T {
+        let (l, r) = to_half_open(range, self.len());
+        self.prefix_sum(r) - self.prefix_sum(l)
+    }
+
+    /// The smallest `p` with `sum(..=p) >= k`, or `len()` if the total is below `k`. With counts
+    /// as values, this is the index of the `k`-th item, counting from 1. The values must be
+    /// non-negative.
+    pub fn lower_bound(&self, k: T) -> usize
+    where
+        T: PartialOrd,
+    {
+        let n = self.len();
+        let (mut p, mut acc) = (0, T::default());
+        let mut step = n.next_power_of_two();
+        while step > 0 {
+            if p + step <= n && acc + self.data[p + step - 1] < k {
+                p += step;
+                acc = acc + self.data[p - 1];
+            }
+            step >>= 1;
+        }
+        p
+    }
+}
+
+impl<T> From<Vec<T>> for FenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Builds the tree in O(n).
+    fn from(mut data: Vec<T>) -> Self {
+        let n = data.len();
+        for i in 1..=n {
+            let j = i + (i & i.wrapping_neg());
+            if j <= n {
+                data[j - 1] = data[j - 1] + data[i - 1];
+            }
+        }
+        FenwickTree { data }
+    }
+}
+
+/// Range additions and range sums, with two Fenwick trees for the prefix sums `a r + b`.
+///
+/// `T` is signed or a `ModInt`, converted from the indices.
+#[derive(Clone, Debug)]
+pub struct RangeFenwickTree<T> {
+    slope: FenwickTree<T>,
+    offset: FenwickTree<T>,
+}
+
+impl<T> RangeFenwickTree<T>
+where
+    T: Copy
+        + Default
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + TryFrom<usize>,
+    <T as TryFrom<usize>>::Error: Debug,
+{
+    /// `n` zeros.
+    pub fn new(n: usize) -> Self {
+        RangeFenwickTree {
+            slope: FenwickTree::new(n),
+            offset: FenwickTree::new(n),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slope.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slope.is_empty()
+    }
+
+    /// Adds `x` to every item of the range.
+    pub fn add(&mut self, range: impl RangeBounds<usize>, x: T) {
+        let (l, r) = to_half_open(range, self.len());
+        if l == r {
+            return;
+        }
+        // from l on, the prefix sum up to i grows by x (i - l)
+        self.slope.add(l, x);
+        self.offset.add(l, -(x * Self::index(l)));
+        if r < self.len() {
+            self.slope.add(r, -x);
+            self.offset.add(r, x * Self::index(r));
+        }
+    }
+
+    /// The sum of the first `r` items.
+    pub fn prefix_sum(&self, r: usize) -> T {
+        self.slope.prefix_sum(r) * Self::index(r) + self.offset.prefix_sum(r)
+    }
+
+    pub fn sum(&self, range: impl RangeBounds<usize>) -> T {
+        let (l, r) = to_half_open(range, self.len());
+        self.prefix_sum(r) - self.prefix_sum(l)
+    }
+
+    fn index(i: usize) -> T {
+        T::try_from(i).expect("index out of the range of the value type")
+    }
+}
+
+impl<T> From<Vec<T>> for RangeFenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Builds the tree in O(n).
+    fn from(values: Vec<T>) -> Self {
+        let n = values.len();
+        RangeFenwickTree {
+            slope: FenwickTree::new(n),
+            offset: FenwickTree::from(values),
+        }
+    }
+}
+
+/// Point additions and rectangle sums on an `h × w` grid.
+#[derive(Clone, Debug)]
+pub struct FenwickTree2D<T> {
+    h: usize,
+    w: usize,
+    data: Vec<T>,
+}
+
+impl<T> FenwickTree2D<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// `h × w` zeros.
+    pub fn new(h: usize, w: usize) -> Self {
+        FenwickTree2D {
+            h,
+            w,
+            data: vec![T::default(); h * w],
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    pub fn add(&mut self, i: usize, j: usize, x: T) {
+        assert!(
+            i < self.h && j < self.w,
+            "cell ({}, {}) out of {}×{}",
+            i,
+            j,
+            self.h,
+            self.w
+        );
+        let mut row = i + 1;
+        while row <= self.h {
+            let mut col = j + 1;
+            while col <= self.w {
+                let cell = &mut self.data[(row - 1) * self.w + col - 1];
+                *cell = *cell + x;
+                col += col & col.wrapping_neg();
+            }
+            row += row & row.wrapping_neg();
+        }
+    }
+
+    /// The sum of the cells in rows `0..i` and columns `0..j`.
+    pub fn prefix_sum(&self, i: usize, j: usize) -> T {
+        assert!(
+            i <= self.h && j <= self.w,
+            "prefix ({}, {}) out of {}×{}",
+            i,
+            j,
+            self.h,
+            self.w
+        );
+        let mut sum = T::default();
+        let mut row = i;
+        while row > 0 {
+            let mut col = j;
+            while col > 0 {
+                sum = sum + self.data[(row - 1) * self.w + col - 1];
+                col &= col - 1;
+            }
+            row &= row - 1;
+        }
+        sum
+    }
+
+    pub fn sum(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> T {
+        let (top, bottom) = to_half_open(rows, self.h);
+        let (left, right) = to_half_open(cols, self.w);
+        // Add before subtracting, so unsigned sums never go below zero.
+        (self.prefix_sum(bottom, right) + self.prefix_sum(top, left))
+            - (self.prefix_sum(top, right) + self.prefix_sum(bottom, left))
+    }
+}
+
+/// The number of pairs `i < j` with `a[i] > a[j]`, in O(n log n).
+pub fn inversion_count<T: Ord>(a: &[T]) -> u64 {
+    let mut sorted = a.iter().collect::<Vec<_>>();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let mut seen = FenwickTree::<u64>::new(sorted.len());
+    let mut count = 0;
+    for (i, x) in a.iter().enumerate() {
+        let rank = sorted.binary_search(&x).unwrap();
+        // the earlier items greater than x
+        count += i as u64 - seen.prefix_sum(rank + 1);
+        seen.add(rank, 1);
+    }
+    count
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modint::Mint998;
+    use crate::rng::{random_range, XorShift64};
+
+    #[test]
+    fn fenwick_vs_naive() {
+        let mut rng = XorShift64::new(24);
+        for n in [0, 1, 2, 7, 8, 50] {
+            let mut naive = (0..n).map(|_| rng.range_i64(-100, 100)).collect::<Vec<_>>();
+            let mut tree = FenwickTree::from(naive.clone());
+            assert_eq!(tree.len(), n);
+            for _ in 0..300 {
+                if n > 0 {
+                    let p = rng.below(n as u64) as usize;
+                    let x = rng.range_i64(-100, 100);
+                    naive[p] += x;
+                    tree.add(p, x);
+                }
+                let (l, r) = random_range(&mut rng, n);
+                assert_eq!(tree.sum(l..r), naive[l..r].iter().sum::<i64>());
+                assert_eq!(tree.prefix_sum(r), naive[..r].iter().sum::<i64>());
+            }
+            assert_eq!(tree.sum(..), naive.iter().sum::<i64>());
+        }
+    }
+
+    #[test]
+    fn fenwick_lower_bound_vs_naive() {
+        let mut rng = XorShift64::new(25);
+        for n in [0, 1, 5, 16, 37] {
+            let counts = (0..n).map(|_| rng.below(4)).collect::<Vec<_>>();
+            let tree = FenwickTree::from(counts.clone());
+            let total = counts.iter().sum::<u64>();
+            for k in 0..=total + 1 {
+                let expected = (0..n)
+                    .find(|&p| counts[..=p].iter().sum::<u64>() >= k)
+                    .unwrap_or(n);
+                assert_eq!(tree.lower_bound(k), expected, "{:?} {}", counts, k);
+            }
+        }
+    }
+
+    #[test]
+    fn fenwick_modint() {
+        let mut tree = FenwickTree::<Mint998>::new(3);
+        tree.add(0, Mint998::new(998_244_352));
+        tree.add(2, Mint998::new(5));
+        assert_eq!(tree.sum(..), Mint998::new(4));
+    }
+
+    #[test]
+    fn range_fenwick_vs_naive() {
+        let mut rng = XorShift64::new(26);
+        for n in [1, 2, 9, 32, 45] {
+            let mut naive = (0..n).map(|_| rng.range_i64(-100, 100)).collect::<Vec<_>>();
+            let mut tree = RangeFenwickTree::from(naive.clone());
+            for _ in 0..300 {
+                let (l, r) = random_range(&mut rng, n);
+                let x = rng.range_i64(-100, 100);
+                naive[l..r].iter_mut().for_each(|v| *v += x);
+                tree.add(l..r, x);
+                let (l, r) = random_range(&mut rng, n);
+                assert_eq!(tree.sum(l..r), naive[l..r].iter().sum::<i64>());
+            }
+            assert_eq!(tree.sum(n - 1..=n - 1), naive[n - 1]);
+        }
+    }
+
+    #[test]
+    fn range_fenwick_modint() {
+        let mut tree = RangeFenwickTree::<Mint998>::new(4);
+        tree.add(1.., Mint998::new(998_244_352));
+        tree.add(..=2, Mint998::new(2));
+        // [2, 1, 1, -1]
+        assert_eq!(tree.sum(..), Mint998::new(3));
+        assert_eq!(tree.sum(3..), -Mint998::one());
+    }
+
+    #[test]
+    fn fenwick_2d_vs_naive() {
+        let mut rng = XorShift64::new(27);
+        let (h, w) = (7, 12);
+        let mut naive = vec![vec![0i64; w]; h];
+        let mut tree = FenwickTree2D::new(h, w);
+        assert_eq!((tree.height(), tree.width()), (h, w));
+        for _ in 0..500 {
+            let (i, j) = (rng.below(h as u64) as usize, rng.below(w as u64) as usize);
+            let x = rng.range_i64(-10, 10);
+            naive[i][j] += x;
+            tree.add(i, j, x);
+            let (top, bottom) = random_range(&mut rng, h);
+            let (left, right) = random_range(&mut rng, w);
+            let expected = naive[top..bottom]
+                .iter()
+                .map(|row| row[left..right].iter().sum::<i64>())
+                .sum::<i64>();
+            assert_eq!(tree.sum(top..bottom, left..right), expected);
+        }
+    }
+
+    #[test]
+    fn fenwick_2d_unsigned_interior() {
+        let mut tree = FenwickTree2D::<u64>::new(3, 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                tree.add(i, j, 1);
+            }
+        }
+        assert_eq!(tree.sum(1..2, 1..2), 1);
+        assert_eq!(tree.sum(1.., 1..), 4);
+        assert_eq!(tree.sum(.., ..), 9);
+    }
+
+    #[test]
+    fn inversion_count_vs_naive() {
+        let mut rng = XorShift64::new(28);
+        assert_eq!(inversion_count::<i32>(&[]), 0);
+        assert_eq!(inversion_count(&[3, 1, 2]), 2);
+        assert_eq!(inversion_count(&["b", "a", "b", "a"]), 3);
+        for n in [1, 10, 100] {
+            let a = (0..n).map(|_| rng.below(20)).collect::<Vec<_>>();
+            let naive = (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| a[i] > a[j])
+                .count() as u64;
+            assert_eq!(inversion_count(&a), naive);
+        }
+        let reversed = (0..1000).rev().collect::<Vec<_>>();
+        assert_eq!(inversion_count(&reversed), 1000 * 999 / 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of")]
+    fn invalid_add_beyond_end() {
+        FenwickTree::<i64>::new(3).add(3, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of")]
+    fn invalid_range_reversed() {
+        let (l, r) = (2, 1);
+        RangeFenwickTree::<i64>::new(3).sum(l..r);
+    }
+}
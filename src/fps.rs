@@ -0,0 +1,486 @@
+//! Formal power series modulo an NTT-friendly prime, built on `convolution()`.
+//!
+//! A series is its coefficient list, lowest degree first. Operations on infinite series like
+//! `inv()`, `log()` and `exp()` take the number `n` of coefficients to compute, by Newton's method
+//! in O(n log n). Polynomial operations like `div_rem()` work on the exact coefficient list.
+//!
+//! ```
+//! use atcoder_rust::fps::Fps998;
+//!
+//! // 1 / (1 - x - x^2) generates the Fibonacci numbers
+//! let f = Fps998::from_u64(&[1, 998244352, 998244352]);
+//! let fib = f.inv(8).coefficients().iter().map(|c| c.value()).collect::<Vec<_>>();
+//! assert_eq!(fib, [1, 1, 2, 3, 5, 8, 13, 21]);
+//! ```
+
+use crate::combination::Combination;
+use crate::convolution::convolution;
+use crate::modint::{ModInt, MODULUS_998};
+use std::ops::{Add, Mul, Neg, Sub};
+
+pub type Fps998 = FormalPowerSeries<MODULUS_998>;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormalPowerSeries<const M: u32> {
+    coef: Vec<ModInt<M>>,
+}
+
+impl<const M: u32> FormalPowerSeries<M> {
+    pub fn new(coef: Vec<ModInt<M>>) -> Self {
+        FormalPowerSeries { coef }
+    }
+
+    pub fn from_u64(coef: &[u64]) -> Self {
+        FormalPowerSeries::new(coef.iter().map(|&c| ModInt::new(c)).collect())
+    }
+
+    pub fn zero(n: usize) -> Self {
+        FormalPowerSeries::new(vec![ModInt::zero(); n])
+    }
+
+    pub fn coefficients(&self) -> &[ModInt<M>] {
+        &self.coef
+    }
+
+    pub fn len(&self) -> usize {
+        self.coef.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coef.is_empty()
+    }
+
+    /// The coefficient of `x^i`, zero beyond the list.
+    pub fn coefficient(&self, i: usize) -> ModInt<M> {
+        self.coef.get(i).copied().unwrap_or_else(ModInt::zero)
+    }
+
+    /// The first `n` coefficients, padded with zeros.
+    pub fn prefix(&self, n: usize) -> Self {
+        FormalPowerSeries::new((0..n).map(|i| self.coefficient(i)).collect())
+    }
+
+    /// Without the trailing zero coefficients.
+    pub fn trimmed(mut self) -> Self {
+        while self.coef.last() == Some(&ModInt::zero()) {
+            self.coef.pop();
+        }
+        self
+    }
+
+    fn reversed(&self) -> Self {
+        FormalPowerSeries::new(self.coef.iter().rev().copied().collect())
+    }
+
+    /// The product truncated to `n` coefficients.
+    pub fn mul_prefix(&self, other: &Self, n: usize) -> Self {
+        let a = &self.coef[..self.len().min(n)];
+        let b = &other.coef[..other.len().min(n)];
+        FormalPowerSeries::new(convolution(a, b)).prefix(n)
+    }
+
+    pub fn derivative(&self) -> Self {
+        let coef = (1..self.len()).map(|i| self.coef[i] * ModInt::from(i));
+        FormalPowerSeries::new(coef.collect())
+    }
+
+    /// The antiderivative with constant term zero.
+    pub fn integral(&self) -> Self {
+        let comb = Combination::<M>::new(self.len());
+        let coef = self
+            .coef
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| c * comb.inv(i + 1));
+        FormalPowerSeries::new(Some(ModInt::zero()).into_iter().chain(coef).collect())
+    }
+
+    /// The polynomial value at `x`, by Horner's method.
+    pub fn evaluate(&self, x: ModInt<M>) -> ModInt<M> {
+        self.coef
+            .iter()
+            .rev()
+            .fold(ModInt::zero(), |acc, &c| acc * x + c)
+    }
+
+    /// `1 / f mod x^n`, the constant term must not be zero.
+    pub fn inv(&self, n: usize) -> Self {
+        assert!(
+            self.coefficient(0) != ModInt::zero(),
+            "no inverse with constant term 0"
+        );
+        let mut g = FormalPowerSeries::new(vec![self.coef[0].inv()]);
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            // g = g (2 - f g)
+            let mut t = -self.mul_prefix(&g, k);
+            t.coef[0] += ModInt::new(2);
+            g = g.mul_prefix(&t, k);
+        }
+        g.prefix(n)
+    }
+
+    /// `log(f) mod x^n`, the constant term must be one.
+    pub fn log(&self, n: usize) -> Self {
+        assert!(
+            self.coefficient(0) == ModInt::one(),
+            "no log with constant term other than 1"
+        );
+        if n == 0 {
+            return FormalPowerSeries::zero(0);
+        }
+        let quotient = self.prefix(n).derivative().mul_prefix(&self.inv(n), n - 1);
+        quotient.integral()
+    }
+
+    /// `exp(f) mod x^n`, the constant term must be zero.
+    pub fn exp(&self, n: usize) -> Self {
+        assert!(
+            self.coefficient(0) == ModInt::zero(),
+            "no exp with constant term other than 0"
+        );
+        let mut g = FormalPowerSeries::new(vec![ModInt::one()]);
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            // g = g (1 - log g + f)
+            let mut t = self.prefix(k) - g.log(k);
+            t.coef[0] += ModInt::one();
+            g = g.mul_prefix(&t, k);
+        }
+        g.prefix(n)
+    }
+
+    /// `f^k mod x^n`
+    pub fn pow(&self, k: u64, n: usize) -> Self {
+        if k == 0 {
+            return FormalPowerSeries::new(vec![ModInt::one()]).prefix(n);
+        }
+        let Some(low) = self.coef.iter().position(|&c| c != ModInt::zero()) else {
+            return FormalPowerSeries::zero(n);
+        };
+        if low as u128 * k as u128 >= n as u128 {
+            return FormalPowerSeries::zero(n);
+        }
+        // f = c x^low h with h(0) = 1, so f^k = c^k x^(low k) exp(k log h)
+        let shift = low * k as usize;
+        let c = self.coef[low];
+        let c_inv = c.inv();
+        let h = FormalPowerSeries::new(self.coef[low..].iter().map(|&x| x * c_inv).collect());
+        let m = n - shift;
+        let k_mod = ModInt::from(k);
+        let g = FormalPowerSeries::new(h.log(m).coef.into_iter().map(|x| x * k_mod).collect());
+        let c_k = c.pow(k);
+        let coef = vec![ModInt::zero(); shift]
+            .into_iter()
+            .chain(g.exp(m).coef.into_iter().map(|x| x * c_k));
+        FormalPowerSeries::new(coef.collect())
+    }
+
+    /// A square root `mod x^n`, `None` if there is none: the lowest term must be an even power
+    /// with a quadratic residue coefficient.
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        let low = match self.coef.iter().position(|&c| c != ModInt::zero()) {
+            Some(low) if low < 2 * n => low,
+            _ => return Some(FormalPowerSeries::zero(n)),
+        };
+        if low % 2 == 1 {
+            return None;
+        }
+        let c = self.coef[low];
+        let root = c.sqrt()?;
+        let c_inv = c.inv();
+        let m = n - low / 2;
+        let h = FormalPowerSeries::new(self.coef[low..].iter().map(|&x| x * c_inv).collect());
+        // g = (g + h / g) / 2
+        let half = ModInt::new(2).inv();
+        let mut g = FormalPowerSeries::new(vec![ModInt::one()]);
+        let mut k = 1;
+        while k < m {
+            k *= 2;
+            let t = g.clone() + h.mul_prefix(&g.inv(k), k);
+            g = FormalPowerSeries::new(t.coef.into_iter().map(|x| x * half).collect());
+        }
+        let coef = vec![ModInt::zero(); low / 2]
+            .into_iter()
+            .chain(g.prefix(m).coef.into_iter().map(|x| x * root));
+        Some(FormalPowerSeries::new(coef.collect()))
+    }
+
+    /// Polynomial division `(quotient, remainder)`, both trimmed; `divisor` must not be zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let f = self.clone().trimmed();
+        let g = divisor.clone().trimmed();
+        assert!(!g.is_empty(), "division by the zero polynomial");
+        if f.len() < g.len() {
+            return (FormalPowerSeries::zero(0), f);
+        }
+        // reversed, the quotient is the leading part of rev(f) / rev(g)
+        let q_len = f.len() - g.len() + 1;
+        let q_rev = f.reversed().mul_prefix(&g.reversed().inv(q_len), q_len);
+        let q = q_rev.reversed();
+        let r = (f - g.mul_prefix(&q, g.len() - 1)).prefix(g.len() - 1);
+        (q.trimmed(), r.trimmed())
+    }
+
+    /// The polynomial values at all the `points`, in O(n log^2 n) with a subproduct tree.
+    pub fn multipoint_evaluate(&self, points: &[ModInt<M>]) -> Vec<ModInt<M>> {
+        if points.is_empty() {
+            return Vec::new();
+        }
+        let size = points.len().next_power_of_two();
+        let mut tree = vec![FormalPowerSeries::new(vec![ModInt::one()]); 2 * size];
+        for (i, &p) in points.iter().enumerate() {
+            tree[size + i] = FormalPowerSeries::new(vec![-p, ModInt::one()]);
+        }
+        for i in (1..size).rev() {
+            tree[i] = tree[2 * i].clone() * tree[2 * i + 1].clone();
+        }
+        // f mod (x - p) = f(p), going down the tree
+        let mut rem = vec![FormalPowerSeries::zero(0); 2 * size];
+        rem[1] = self.div_rem(&tree[1]).1;
+        for i in 2..size + points.len() {
+            rem[i] = rem[i / 2].div_rem(&tree[i]).1;
+        }
+        (0..points.len())
+            .map(|i| rem[size + i].coefficient(0))
+            .collect()
+    }
+
+    /// `f(x + c)`
+    pub fn taylor_shift(&self, c: ModInt<M>) -> Self {
+        let n = self.len();
+        if n == 0 {
+            return self.clone();
+        }
+        let comb = Combination::<M>::new(n);
+        // b_i = 1/i! sum_j (a_j j!) (c^(j-i) / (j-i)!)
+        let a = (0..n)
+            .rev()
+            .map(|j| self.coef[j] * comb.fact(j))
+            .collect::<Vec<_>>();
+        let mut c_pow = ModInt::one();
+        let mut e = Vec::with_capacity(n);
+        for k in 0..n {
+            e.push(c_pow * comb.inv_fact(k));
+            c_pow *= c;
+        }
+        let product = convolution(&a, &e);
+        let coef = (0..n).map(|i| product[n - 1 - i] * comb.inv_fact(i));
+        FormalPowerSeries::new(coef.collect())
+    }
+}
+
+impl<const M: u32> Add for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let n = self.len().max(rhs.len());
+        let coef = (0..n).map(|i| self.coefficient(i) + rhs.coefficient(i));
+        FormalPowerSeries::new(coef.collect())
+    }
+}
+
+impl<const M: u32> Sub for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const M: u32> Neg for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        FormalPowerSeries::new(self.coef.into_iter().map(|c| -c).collect())
+    }
+}
+
+/// The full polynomial product, use `mul_prefix()` for truncated series.
+impl<const M: u32> Mul for FormalPowerSeries<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        FormalPowerSeries::new(convolution(&self.coef, &rhs.coef))
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modint::Mint998;
+    use crate::rng::XorShift64;
+
+    fn random_fps(rng: &mut XorShift64, n: usize) -> Fps998 {
+        FormalPowerSeries::new((0..n).map(|_| Mint998::new(rng.next_u64())).collect())
+    }
+
+    /// Naive O(n^2) product truncated to `n` coefficients.
+    fn naive_mul(a: &Fps998, b: &Fps998, n: usize) -> Fps998 {
+        let mut coef = vec![Mint998::zero(); n];
+        for (i, &x) in a.coefficients().iter().enumerate() {
+            for (j, &y) in b.coefficients().iter().enumerate() {
+                if i + j < n {
+                    coef[i + j] += x * y;
+                }
+            }
+        }
+        FormalPowerSeries::new(coef)
+    }
+
+    /// `x^k f`
+    fn shifted(f: &Fps998, k: usize) -> Fps998 {
+        let mut coef = vec![Mint998::zero(); k];
+        coef.extend(f.coefficients());
+        FormalPowerSeries::new(coef)
+    }
+
+    /// `1 mod x^n`
+    fn one(n: usize) -> Fps998 {
+        FormalPowerSeries::from_u64(&[1]).prefix(n)
+    }
+
+    #[test]
+    fn inv_fibonacci_and_random() {
+        let f = Fps998::from_u64(&[1, MODULUS_998 as u64 - 1, MODULUS_998 as u64 - 1]);
+        assert_eq!(
+            f.inv(10),
+            Fps998::from_u64(&[1, 1, 2, 3, 5, 8, 13, 21, 34, 55])
+        );
+        let mut rng = XorShift64::new(20);
+        for n in [1, 2, 7, 64, 100] {
+            let mut f = random_fps(&mut rng, n);
+            f.coef[0] = Mint998::new(5);
+            assert_eq!(naive_mul(&f, &f.inv(n), n), one(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn log_and_exp_known_series() {
+        let comb = Combination::<MODULUS_998>::new(20);
+        // exp(x) = sum x^k / k!
+        let x = Fps998::from_u64(&[0, 1]);
+        let expected = (0..20).map(|k| comb.inv_fact(k)).collect::<Vec<_>>();
+        assert_eq!(x.exp(20).coefficients(), expected);
+        // log(1 / (1 - x)) = sum x^k / k
+        let geometric = Fps998::from_u64(&[1; 20]);
+        let expected = (0..20)
+            .map(|k| if k == 0 { Mint998::zero() } else { comb.inv(k) })
+            .collect::<Vec<_>>();
+        assert_eq!(geometric.log(20).coefficients(), expected);
+    }
+
+    #[test]
+    fn exp_inverts_log() {
+        let mut rng = XorShift64::new(21);
+        for n in [1, 5, 33, 100] {
+            let mut f = random_fps(&mut rng, n);
+            f.coef[0] = Mint998::one();
+            assert_eq!(f.log(n).exp(n), f, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn pow_vs_repeated_product() {
+        let mut rng = XorShift64::new(22);
+        let n = 30;
+        for low in [0, 1, 3] {
+            let mut f = shifted(&random_fps(&mut rng, 10), low);
+            f.coef[low] = Mint998::new(3);
+            let mut expected = one(n);
+            for k in 0..12 {
+                assert_eq!(f.pow(k, n), expected, "low = {}, k = {}", low, k);
+                expected = naive_mul(&expected, &f, n);
+            }
+        }
+        assert_eq!(Fps998::zero(3).pow(0, 3), one(3));
+        assert_eq!(Fps998::zero(3).pow(2, 3), Fps998::zero(3));
+        assert_eq!(Fps998::from_u64(&[0, 1]).pow(u64::MAX, 3), Fps998::zero(3));
+    }
+
+    #[test]
+    fn sqrt_squares_back() {
+        let mut rng = XorShift64::new(23);
+        for low in [0, 2, 4] {
+            let mut g = shifted(&random_fps(&mut rng, 20), low / 2);
+            g.coef[low / 2] = Mint998::new(7);
+            let f = naive_mul(&g, &g, 30);
+            let root = f.sqrt(30).unwrap();
+            assert_eq!(naive_mul(&root, &root, 30), f, "low = {}", low);
+        }
+        assert_eq!(Fps998::from_u64(&[0, 1]).sqrt(5), None);
+        // 3 is a non-residue modulo 998244353
+        assert_eq!(Fps998::from_u64(&[3, 1]).sqrt(5), None);
+        assert_eq!(Fps998::zero(4).sqrt(4), Some(Fps998::zero(4)));
+    }
+
+    #[test]
+    fn div_rem_reconstructs() {
+        let mut rng = XorShift64::new(24);
+        for (n, m) in [(10, 3), (100, 70), (5, 5), (3, 10), (200, 1)] {
+            let f = random_fps(&mut rng, n);
+            let mut g = random_fps(&mut rng, m);
+            g.coef[m - 1] = Mint998::one();
+            let (q, r) = f.div_rem(&g);
+            assert!(r.len() < g.len());
+            assert_eq!((q * g + r).trimmed(), f.clone().trimmed(), "{} / {}", n, m);
+        }
+        // (x^2 - 1) / (x - 1) = x + 1
+        let f = Fps998::from_u64(&[MODULUS_998 as u64 - 1, 0, 1]);
+        let g = Fps998::from_u64(&[MODULUS_998 as u64 - 1, 1, 0]);
+        assert_eq!(f.div_rem(&g), (Fps998::from_u64(&[1, 1]), Fps998::zero(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero polynomial")]
+    fn invalid_div_rem_by_zero() {
+        Fps998::from_u64(&[1, 2]).div_rem(&Fps998::zero(3));
+    }
+
+    #[test]
+    fn multipoint_evaluate_vs_horner() {
+        let mut rng = XorShift64::new(25);
+        for (n, m) in [(1, 1), (10, 3), (50, 77), (200, 100)] {
+            let f = random_fps(&mut rng, n);
+            let points = (0..m)
+                .map(|_| Mint998::new(rng.next_u64()))
+                .collect::<Vec<_>>();
+            let expected = points.iter().map(|&p| f.evaluate(p)).collect::<Vec<_>>();
+            assert_eq!(f.multipoint_evaluate(&points), expected, "{} at {}", n, m);
+        }
+        assert!(Fps998::from_u64(&[1]).multipoint_evaluate(&[]).is_empty());
+    }
+
+    #[test]
+    fn taylor_shift_vs_binomial_expansion() {
+        let mut rng = XorShift64::new(26);
+        let comb = Combination::<MODULUS_998>::new(100);
+        for n in [1, 2, 40, 100] {
+            let f = random_fps(&mut rng, n);
+            let c = Mint998::new(rng.next_u64());
+            // sum_j a_j (x + c)^j, expanded naively
+            let mut expected = Fps998::zero(n);
+            for j in 0..n {
+                for i in 0..=j {
+                    expected.coef[i] += f.coef[j] * comb.binom(j, i) * c.pow((j - i) as u64);
+                }
+            }
+            let shifted = f.taylor_shift(c);
+            assert_eq!(shifted, expected, "n = {}", n);
+            assert_eq!(
+                shifted.evaluate(Mint998::new(5)),
+                f.evaluate(Mint998::new(5) + c)
+            );
+        }
+    }
+
+    #[test]
+    fn derivative_and_integral() {
+        let f = Fps998::from_u64(&[5, 1, 2, 3]);
+        assert_eq!(f.derivative(), Fps998::from_u64(&[1, 4, 9]));
+        assert_eq!(f.derivative().integral(), Fps998::from_u64(&[0, 1, 2, 3]));
+    }
+}
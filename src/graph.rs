@@ -0,0 +1,472 @@
+//! Graphs over the vertices `0..n` as adjacency lists, with the usual traversals.
+//!
+//! Edges are read from `M` lines `u v` or `u v w` of 1-based vertices. The traversals are
+//! iterative, so paths of `2·10^5` vertices do not overflow the stack.
+//!
+//! ```
+//! use atcoder_rust::graph::Graph;
+//! use atcoder_rust::scanner::Scanner;
+//!
+//! let mut sc = Scanner::new("1 2\n2 3\n".as_bytes());
+//! let mut graph = Graph::undirected(4);
+//! graph.read_edges(&mut sc, 2).unwrap();
+//! assert_eq!(graph.bfs(0), [Some(0), Some(1), Some(2), None]);
+//! assert_eq!(graph.components(), [vec![0, 1, 2], vec![3]]);
+//! ```
+
+use crate::error::PuzzleError;
+use crate::scanner::{FromToken, Scanner};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+/// Adjacency lists of `(neighbor, weight)`, `W` is `()` for unweighted graphs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Graph<W = ()> {
+    adj: Vec<Vec<(usize, W)>>,
+    directed: bool,
+}
+
+/// The visiting orders of a depth-first search from one vertex.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dfs {
+    pub preorder: Vec<usize>,
+    pub postorder: Vec<usize>,
+    /// The vertex a vertex was first reached from, `None` for the source and unreached vertices.
+    pub parent: Vec<Option<usize>>,
+}
+
+/// Reads a 1-based vertex, returning it 0-based.
+fn next_vertex<R: BufRead>(
+    sc: &mut Scanner<R>,
+    n: usize,
+    field: &str,
+) -> Result<usize, PuzzleError> {
+    let v = sc.next_usize1(field)?;
+    if v >= n {
+        return Err(PuzzleError::Constraint {
+            field: field.to_string(),
+            value: (v + 1).to_string(),
+            range: format!("1..={}", n),
+        });
+    }
+    Ok(v)
+}
+
+impl<W: Clone> Graph<W> {
+    /// `n` vertices, each edge usable both ways.
+    pub fn undirected(n: usize) -> Self {
+        Graph {
+            adj: vec![Vec::new(); n],
+            directed: false,
+        }
+    }
+
+    /// `n` vertices, each edge usable from `u` to `v` only.
+    pub fn directed(n: usize) -> Self {
+        Graph {
+            adj: vec![Vec::new(); n],
+            directed: true,
+        }
+    }
+
+    /// The number of vertices.
+    pub fn len(&self) -> usize {
+        self.adj.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adj.is_empty()
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn add_edge(&mut self, u: usize, v: usize, w: W) {
+        let n = self.len();
+        assert!(u < n && v < n, "edge {}-{} out of 0..{}", u, v, n);
+        if !self.directed {
+            self.adj[v].push((u, w.clone()));
+        }
+        self.adj[u].push((v, w));
+    }
+
+    /// The edges leaving `v`, as `(neighbor, weight)` in insertion order.
+    pub fn edges(&self, v: usize) -> &[(usize, W)] {
+        &self.adj[v]
+    }
+
+    pub fn neighbors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj[v].iter().map(|&(u, _)| u)
+    }
+
+    /// The number of edges from `source` to each vertex, `None` if unreachable.
+    pub fn bfs(&self, source: usize) -> Vec<Option<usize>> {
+        let mut dist = vec![None; self.len()];
+        dist[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            let d = dist[v].map(|d| d + 1);
+            for u in self.neighbors(v) {
+                if dist[u].is_none() {
+                    dist[u] = d;
+                    queue.push_back(u);
+                }
+            }
+        }
+        dist
+    }
+
+    /// Depth-first search from `source`, in the order of a recursive one.
+    pub fn dfs(&self, source: usize) -> Dfs {
+        let n = self.len();
+        let mut visited = vec![false; n];
+        let mut dfs = Dfs {
+            preorder: vec![source],
+            postorder: Vec::new(),
+            parent: vec![None; n],
+        };
+        visited[source] = true;
+        // the vertices on the current path, with the index of their next edge
+        let mut stack = vec![(source, 0)];
+        while let Some((v, i)) = stack.last_mut() {
+            let v = *v;
+            match self.adj[v].get(*i) {
+                Some(&(u, _)) => {
+                    *i += 1;
+                    if !visited[u] {
+                        visited[u] = true;
+                        dfs.parent[u] = Some(v);
+                        dfs.preorder.push(u);
+                        stack.push((u, 0));
+                    }
+                }
+                None => {
+                    dfs.postorder.push(v);
+                    stack.pop();
+                }
+            }
+        }
+        dfs
+    }
+
+    /// An order of the vertices where every edge goes forward, `None` if there is a cycle.
+    ///
+    /// Panics on an undirected graph.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        assert!(self.directed, "topological sort of an undirected graph");
+        let mut in_degree = vec![0; self.len()];
+        for v in 0..self.len() {
+            for u in self.neighbors(v) {
+                in_degree[u] += 1;
+            }
+        }
+        let mut order = (0..self.len())
+            .filter(|&v| in_degree[v] == 0)
+            .collect::<Vec<_>>();
+        let mut head = 0;
+        while let Some(&v) = order.get(head) {
+            head += 1;
+            for u in self.neighbors(v) {
+                in_degree[u] -= 1;
+                if in_degree[u] == 0 {
+                    order.push(u);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// The connected components, each sorted, ordered by their smallest vertex.
+    ///
+    /// Panics on a directed graph.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        assert!(!self.directed, "components of a directed graph");
+        let mut visited = vec![false; self.len()];
+        let mut components = Vec::new();
+        for source in 0..self.len() {
+            if visited[source] {
+                continue;
+            }
+            visited[source] = true;
+            let mut component = vec![source];
+            let mut stack = vec![source];
+            while let Some(v) = stack.pop() {
+                for u in self.neighbors(v) {
+                    if !visited[u] {
+                        visited[u] = true;
+                        component.push(u);
+                        stack.push(u);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// A 2-coloring where every edge joins different colors, the smallest vertex of each
+    /// component being `false`. `None` if there is an odd cycle.
+    ///
+    /// Panics on a directed graph.
+    pub fn bipartition(&self) -> Option<Vec<bool>> {
+        assert!(!self.directed, "bipartition of a directed graph");
+        let mut color = vec![None; self.len()];
+        for source in 0..self.len() {
+            if color[source].is_some() {
+                continue;
+            }
+            color[source] = Some(false);
+            let mut stack = vec![source];
+            while let Some(v) = stack.pop() {
+                let c = color[v];
+                for u in self.neighbors(v) {
+                    match color[u] {
+                        None => {
+                            color[u] = c.map(|c| !c);
+                            stack.push(u);
+                        }
+                        Some(_) if color[u] == c => return None,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        color.into_iter().collect()
+    }
+}
+
+impl Graph {
+    /// Adds `m` edges read as lines `u v` of 1-based vertices.
+    pub fn read_edges<R: BufRead>(
+        &mut self,
+        sc: &mut Scanner<R>,
+        m: usize,
+    ) -> Result<(), PuzzleError> {
+        for _ in 0..m {
+            let u = next_vertex(sc, self.len(), "u")?;
+            let v = next_vertex(sc, self.len(), "v")?;
+            self.add_edge(u, v, ());
+        }
+        Ok(())
+    }
+}
+
+impl<W: Clone + FromToken> Graph<W> {
+    /// Adds `m` edges read as lines `u v w` of 1-based vertices and a weight.
+    pub fn read_weighted_edges<R: BufRead>(
+        &mut self,
+        sc: &mut Scanner<R>,
+        m: usize,
+    ) -> Result<(), PuzzleError> {
+        for _ in 0..m {
+            let u = next_vertex(sc, self.len(), "u")?;
+            let v = next_vertex(sc, self.len(), "v")?;
+            let w = sc.next("w")?;
+            self.add_edge(u, v, w);
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsu::Dsu;
+    use crate::rng::XorShift64;
+
+    fn random_graph(rng: &mut XorShift64, n: usize, m: usize, directed: bool) -> Graph {
+        let mut graph = if directed {
+            Graph::directed(n)
+        } else {
+            Graph::undirected(n)
+        };
+        for _ in 0..m {
+            let u = rng.below(n as u64) as usize;
+            let v = rng.below(n as u64) as usize;
+            graph.add_edge(u, v, ());
+        }
+        graph
+    }
+
+    #[test]
+    fn read_weighted_directed() {
+        let mut sc = Scanner::new("1 2 5\n2 3 -1\n3 1 7\n".as_bytes());
+        let mut graph = Graph::<i64>::directed(3);
+        graph.read_weighted_edges(&mut sc, 3).unwrap();
+        assert!(graph.is_directed());
+        assert_eq!(graph.edges(0), [(1, 5)]);
+        assert_eq!(graph.edges(1), [(2, -1)]);
+        assert_eq!(graph.neighbors(2).collect::<Vec<_>>(), [0]);
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn read_unweighted_undirected() {
+        let mut sc = Scanner::new("1 2\n1 3\n".as_bytes());
+        let mut graph = Graph::undirected(3);
+        graph.read_edges(&mut sc, 2).unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.neighbors(0).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(graph.neighbors(2).collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    fn invalid_read_vertex_out_of_range() {
+        let mut sc = Scanner::new("1 2\n2 4\n".as_bytes());
+        let result = Graph::undirected(3).read_edges(&mut sc, 2);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`v` = 4 violates the constraint 1..=3"
+        );
+    }
+
+    #[test]
+    fn invalid_read_vertex_zero() {
+        let mut sc = Scanner::new("0 2\n".as_bytes());
+        let result = Graph::undirected(3).read_edges(&mut sc, 1);
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { field, .. } if field == "u"
+        ));
+    }
+
+    #[test]
+    fn bfs_vs_relaxation() {
+        let mut rng = XorShift64::new(25);
+        for directed in [false, true] {
+            let n = 30;
+            let graph = random_graph(&mut rng, n, 40, directed);
+            // Bellman-Ford style relaxation with unit weights
+            let mut expected = vec![None; n];
+            expected[0] = Some(0);
+            for _ in 0..n {
+                for v in 0..n {
+                    for u in graph.neighbors(v) {
+                        if let Some(d) = expected[v] {
+                            if expected[u].map_or(true, |e| d + 1 < e) {
+                                expected[u] = Some(d + 1);
+                            }
+                        }
+                    }
+                }
+            }
+            assert_eq!(graph.bfs(0), expected);
+        }
+    }
+
+    #[test]
+    fn dfs_recursive_order() {
+        let mut graph = Graph::directed(6);
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (3, 2), (2, 4), (5, 0)] {
+            graph.add_edge(u, v, ());
+        }
+        let dfs = graph.dfs(0);
+        assert_eq!(dfs.preorder, [0, 1, 3, 2, 4]);
+        assert_eq!(dfs.postorder, [4, 2, 3, 1, 0]);
+        assert_eq!(dfs.parent, [None, Some(0), Some(3), Some(1), Some(2), None]);
+    }
+
+    #[test]
+    fn dfs_long_path() {
+        let n = 200_000;
+        let mut graph = Graph::undirected(n);
+        for v in 1..n {
+            graph.add_edge(v - 1, v, ());
+        }
+        let dfs = graph.dfs(0);
+        assert_eq!(dfs.preorder, (0..n).collect::<Vec<_>>());
+        assert_eq!(dfs.postorder, (0..n).rev().collect::<Vec<_>>());
+        assert_eq!(graph.bfs(0)[n - 1], Some(n - 1));
+    }
+
+    #[test]
+    fn topological_sort_random_dag() {
+        let mut rng = XorShift64::new(26);
+        let n = 50;
+        let mut labels = (0..n).collect::<Vec<_>>();
+        rng.shuffle(&mut labels);
+        let mut graph = Graph::directed(n);
+        for _ in 0..200 {
+            let a = rng.below(n as u64) as usize;
+            let b = rng.below(n as u64) as usize;
+            if a < b {
+                graph.add_edge(labels[a], labels[b], ());
+            }
+        }
+        let order = graph.topological_sort().unwrap();
+        let mut position = vec![usize::MAX; n];
+        for (i, &v) in order.iter().enumerate() {
+            position[v] = i;
+        }
+        assert!(position.iter().all(|&p| p < n));
+        for v in 0..n {
+            assert!(graph.neighbors(v).all(|u| position[v] < position[u]));
+        }
+        // closing a cycle
+        graph.add_edge(order[n - 1], order[0], ());
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn components_vs_dsu() {
+        let mut rng = XorShift64::new(27);
+        for (n, m) in [(1, 0), (20, 10), (100, 60)] {
+            let graph = random_graph(&mut rng, n, m, false);
+            let mut dsu = Dsu::new(graph.len());
+            for v in 0..graph.len() {
+                for u in graph.neighbors(v) {
+                    dsu.merge(u, v);
+                }
+            }
+            assert_eq!(graph.components(), dsu.groups());
+        }
+        assert!(Graph::<()>::undirected(0).components().is_empty());
+    }
+
+    #[test]
+    fn bipartition_vs_brute_force() {
+        let mut rng = XorShift64::new(28);
+        let n = 8;
+        for _ in 0..100 {
+            let graph = random_graph(&mut rng, n, 7, false);
+            let two_colorable = (0..1u32 << n).any(|mask| {
+                (0..n).all(|v| {
+                    graph
+                        .neighbors(v)
+                        .all(|u| (mask >> u & 1) != (mask >> v & 1))
+                })
+            });
+            match graph.bipartition() {
+                Some(color) => {
+                    assert!(two_colorable);
+                    for v in 0..n {
+                        assert!(graph.neighbors(v).all(|u| color[u] != color[v]));
+                    }
+                }
+                None => assert!(!two_colorable),
+            }
+        }
+    }
+
+    #[test]
+    fn bipartition_cycles() {
+        let cycle = |n: usize| {
+            let mut graph = Graph::undirected(n);
+            for v in 0..n {
+                graph.add_edge(v, (v + 1) % n, ());
+            }
+            graph
+        };
+        assert_eq!(cycle(4).bipartition(), Some(vec![false, true, false, true]));
+        assert_eq!(cycle(5).bipartition(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "undirected")]
+    fn invalid_topological_sort_undirected() {
+        Graph::<()>::undirected(2).topological_sort();
+    }
+}
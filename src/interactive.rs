@@ -0,0 +1,556 @@
+//! Local judges for interactive tasks.
+//!
+//! A judge holds the hidden data, answers the queries of the solution line by line, and gives
+//! the verdict on the final answer. `interact()` drives a judge over any reader and writer,
+//! `spawn()` runs a solution binary as a child process with piped `stdin` and `stdout`, and kills
+//! it once the time limit of the judge has passed.
+//!
+//! On the solution side, the `Interactor` trait hides the query transport, so the solution logic
+//! runs either on `stdin` / `stdout` for submission, or in-process against a `MockInteractor`.
+
+use crate::rng::XorShift64;
+use crate::scanner::Scanner;
+use crate::verify::Verdict;
+use crate::{PuzzleError, DEFAULT_TIME_LIMIT};
+use std::cmp::Ordering;
+use std::io;
+use std::io::{BufRead, Read, Write};
+use std::path::Path;
+use std::process;
+use std::sync::mpsc;
+use std::thread;
+use std::time;
+
+/// The judge side of an interactive task.
+pub trait InteractiveJudge {
+    /// The input sent to the solution at the start.
+    fn start(&mut self) -> String;
+    /// Handles an output line of the solution.
+    fn respond(&mut self, line: &str) -> Response;
+    /// The count of queries answered so far.
+    fn queries(&self) -> usize;
+
+    /// The wall-clock limit of a whole run in `spawn()`.
+    fn time_limit(&self) -> time::Duration {
+        DEFAULT_TIME_LIMIT
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Response {
+    /// Line sent back to the solution.
+    Reply(String),
+    /// The final verdict, with the reason if rejected.
+    Verdict(Verdict, String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InteractiveReport {
+    pub verdict: Verdict,
+    pub queries: usize,
+    pub detail: String,
+}
+
+/// Judge of `practice_b`: sorts the first `N` uppercase letters by hidden weights, using at most
+/// `Q` queries `? X Y`, answered with `<` if `X` is lighter, then the answer `! ABC...`.
+pub struct SortingJudge {
+    /// The letters in increasing order of weight.
+    order: Vec<char>,
+    query_limit: usize,
+    queries: usize,
+}
+
+/// The test sets of `practice_b` as `(N, Q)`.
+pub const SORTING_TEST_SETS: &[(usize, usize)] = &[(26, 1000), (26, 100), (5, 7)];
+
+impl SortingJudge {
+    /// `order` lists the first `N` uppercase letters in increasing order of weight.
+    pub fn new(order: &str, query_limit: usize) -> Self {
+        SortingJudge {
+            order: order.chars().collect(),
+            query_limit,
+            queries: 0,
+        }
+    }
+
+    /// Hidden order of the first `n` letters, shuffled by `rng`.
+    pub fn random(n: usize, query_limit: usize, rng: &mut XorShift64) -> Self {
+        let mut order = ('A'..='Z').take(n).collect::<Vec<_>>();
+        rng.shuffle(&mut order);
+        SortingJudge {
+            order,
+            query_limit,
+            queries: 0,
+        }
+    }
+
+    fn rank(&self, token: &str) -> Option<usize> {
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.order.iter().position(|&x| x == c),
+            _ => None,
+        }
+    }
+
+    fn reject(detail: String) -> Response {
+        Response::Verdict(Verdict::WrongAnswer, detail)
+    }
+}
+
+impl InteractiveJudge for SortingJudge {
+    fn start(&mut self) -> String {
+        format!("{} {}", self.order.len(), self.query_limit)
+    }
+
+    fn respond(&mut self, line: &str) -> Response {
+        let tokens = line.split_ascii_whitespace().collect::<Vec<_>>();
+        match tokens.as_slice() {
+            ["?", x, y] => {
+                self.queries += 1;
+                if self.queries > self.query_limit {
+                    return Self::reject(format!("more than {} queries", self.query_limit));
+                }
+                match (self.rank(x), self.rank(y)) {
+                    (Some(rx), Some(ry)) if rx != ry => {
+                        Response::Reply(if rx < ry { "<" } else { ">" }.to_string())
+                    }
+                    _ => Self::reject(format!("invalid query `{}`", line.trim_end())),
+                }
+            }
+            ["!", answer] => {
+                let expected = self.order.iter().collect::<String>();
+                if *answer == expected {
+                    Response::Verdict(Verdict::Accepted, String::new())
+                } else {
+                    Self::reject(format!("answer `{}`, expected `{}`", answer, expected))
+                }
+            }
+            _ => Self::reject(format!("invalid output `{}`", line.trim_end())),
+        }
+    }
+
+    fn queries(&self) -> usize {
+        self.queries
+    }
+}
+
+/// The solution side of a comparison-based interactive task like `practice_b`.
+pub trait Interactor {
+    /// Compares the hidden weights of two items, `Less` if `a` is lighter.
+    fn ask(&mut self, a: char, b: char) -> Result<Ordering, PuzzleError>;
+    /// Sends the items in increasing order of weight, as the final answer.
+    fn answer(&mut self, order: &[char]) -> Result<(), PuzzleError>;
+}
+
+/// `Interactor` over a reader and a writer, e.g. `stdin` and `stdout` for submission.
+pub struct StdioInteractor<R, W> {
+    sc: Scanner<R>,
+    writer: W,
+}
+
+impl<R: BufRead, W: Write> StdioInteractor<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        StdioInteractor {
+            sc: Scanner::new(reader),
+            writer,
+        }
+    }
+
+    /// Reads the first line `N Q`: the item count and the query limit.
+    pub fn read_header(&mut self) -> Result<(usize, usize), PuzzleError> {
+        self.sc.goto_line(0, "n")?;
+        let n = self.sc.next::<usize>("n")?;
+        let q = self.sc.next::<usize>("q")?;
+        self.sc.unlock_line();
+        Ok((n, q))
+    }
+
+    fn send(&mut self, line: &str) -> Result<(), PuzzleError> {
+        writeln!(self.writer, "{}", line)
+            .and_then(|_| self.writer.flush())
+            .map_err(|source| PuzzleError::Io {
+                path: "output".to_string(),
+                source,
+            })
+    }
+}
+
+impl<R: BufRead, W: Write> Interactor for StdioInteractor<R, W> {
+    fn ask(&mut self, a: char, b: char) -> Result<Ordering, PuzzleError> {
+        self.send(&format!("? {} {}", a, b))?;
+        match self.sc.next::<String>("ans")?.as_str() {
+            "<" => Ok(Ordering::Less),
+            ">" => Ok(Ordering::Greater),
+            ans => Err(PuzzleError::Judge(format!(
+                "expected `<` or `>` answer, found `{}`",
+                ans
+            ))),
+        }
+    }
+
+    fn answer(&mut self, order: &[char]) -> Result<(), PuzzleError> {
+        self.send(&format!("! {}", order.iter().collect::<String>()))
+    }
+}
+
+/// In-process `Interactor` answering from the hidden order of a `SortingJudge`.
+///
+/// A rejected query is returned as a `PuzzleError::Judge` error, the final verdict is kept.
+pub struct MockInteractor {
+    judge: SortingJudge,
+    verdict: Option<Verdict>,
+}
+
+impl MockInteractor {
+    pub fn new(judge: SortingJudge) -> Self {
+        MockInteractor {
+            judge,
+            verdict: None,
+        }
+    }
+
+    pub fn queries(&self) -> usize {
+        self.judge.queries()
+    }
+
+    /// The verdict on the answer, or `None` before `answer()`.
+    pub fn verdict(&self) -> Option<Verdict> {
+        self.verdict
+    }
+}
+
+impl Interactor for MockInteractor {
+    fn ask(&mut self, a: char, b: char) -> Result<Ordering, PuzzleError> {
+        match self.judge.respond(&format!("? {} {}", a, b)) {
+            Response::Reply(reply) if reply == "<" => Ok(Ordering::Less),
+            Response::Reply(_) => Ok(Ordering::Greater),
+            Response::Verdict(verdict, detail) => {
+                self.verdict = Some(verdict);
+                Err(PuzzleError::Judge(detail))
+            }
+        }
+    }
+
+    fn answer(&mut self, order: &[char]) -> Result<(), PuzzleError> {
+        let line = format!("! {}", order.iter().collect::<String>());
+        if let Response::Verdict(verdict, _) = self.judge.respond(&line) {
+            self.verdict = Some(verdict);
+        }
+        Ok(())
+    }
+}
+
+fn runtime_error(judge: &dyn InteractiveJudge, detail: &str) -> InteractiveReport {
+    InteractiveReport {
+        verdict: Verdict::RuntimeError,
+        queries: judge.queries(),
+        detail: detail.to_string(),
+    }
+}
+
+/// Runs the judge against a solution reading `to_solution` and writing `from_solution`.
+pub fn interact(
+    judge: &mut dyn InteractiveJudge,
+    to_solution: &mut dyn Write,
+    from_solution: &mut dyn BufRead,
+) -> InteractiveReport {
+    let start = judge.start();
+    if writeln!(to_solution, "{}", start)
+        .and_then(|_| to_solution.flush())
+        .is_err()
+    {
+        return runtime_error(judge, "solution closed its input");
+    }
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match from_solution.read_line(&mut line) {
+            Ok(0) | Err(_) => return runtime_error(judge, "solution exited without an answer"),
+            Ok(_) => {}
+        }
+        match judge.respond(&line) {
+            Response::Reply(reply) => {
+                if writeln!(to_solution, "{}", reply)
+                    .and_then(|_| to_solution.flush())
+                    .is_err()
+                {
+                    return runtime_error(judge, "solution closed its input");
+                }
+            }
+            Response::Verdict(verdict, detail) => {
+                return InteractiveReport {
+                    verdict,
+                    queries: judge.queries(),
+                    detail,
+                }
+            }
+        }
+    }
+}
+
+/// Runs the solution binary as a child process, and judges it with `interact()`.
+///
+/// A solution exiting with an error after an accepted answer gets `RE`, one still running after
+/// `judge.time_limit()` is killed and gets `TLE`. Its `stderr` is read on a separate thread, so a
+/// chatty solution cannot fill the pipe and block.
+pub fn spawn(
+    program: &Path,
+    judge: &mut dyn InteractiveJudge,
+) -> Result<InteractiveReport, PuzzleError> {
+    let io_error = |source| PuzzleError::Io {
+        path: program.display().to_string(),
+        source,
+    };
+    let mut child = process::Command::new(program)
+        .stdin(process::Stdio::piped())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped())
+        .spawn()
+        .map_err(io_error)?;
+    let mut to_solution = child.stdin.take().expect("piped stdin");
+    let mut from_solution = io::BufReader::new(child.stdout.take().expect("piped stdout"));
+    let mut child_stderr = child.stderr.take().expect("piped stderr");
+    let stderr_reader = thread::spawn(move || {
+        let mut stderr = Vec::new();
+        let _ = child_stderr.read_to_end(&mut stderr);
+        String::from_utf8_lossy(&stderr).into_owned()
+    });
+    let time_limit = judge.time_limit();
+    let (kill, killed) = mpsc::channel();
+    let watchdog = thread::spawn(move || watch(child, time_limit, killed));
+
+    let mut report = interact(judge, &mut to_solution, &mut from_solution);
+    drop(to_solution);
+    if report.verdict != Verdict::Accepted {
+        // the solution may still wait for a reply
+        let _ = kill.send(());
+    }
+    let (status, timed_out) = watchdog.join().expect("watchdog thread panicked");
+    let status = status.map_err(io_error)?;
+    let stderr = stderr_reader.join().expect("stderr thread panicked");
+    if timed_out {
+        report.verdict = Verdict::TimeLimitExceeded;
+        report.detail = format!("killed after {:?}", time_limit);
+    } else if report.verdict == Verdict::Accepted && !status.success() {
+        report.verdict = Verdict::RuntimeError;
+        report.detail = format!("exit status {}", status);
+    }
+    if report.verdict == Verdict::RuntimeError {
+        if let Some(last) = stderr.lines().last() {
+            report.detail = format!("{}: {}", report.detail, last);
+        }
+    }
+    Ok(report)
+}
+
+/// Waits for the child to exit, killing it at the time limit or when asked through `kill`.
+///
+/// Returns the exit status, and whether the time limit was hit.
+fn watch(
+    mut child: process::Child,
+    time_limit: time::Duration,
+    kill: mpsc::Receiver<()>,
+) -> (io::Result<process::ExitStatus>, bool) {
+    const POLL: time::Duration = time::Duration::from_millis(5);
+    let deadline = time::Instant::now() + time_limit;
+    let mut timed_out = false;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return (Ok(status), timed_out),
+            Ok(None) => {}
+            Err(e) => return (Err(e), timed_out),
+        }
+        let now = time::Instant::now();
+        if now >= deadline {
+            timed_out = true;
+        } else if kill.recv_timeout(POLL.min(deadline - now)).is_err() {
+            // no kill request yet
+            continue;
+        }
+        let _ = child.kill();
+        return (child.wait(), timed_out);
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interact_str(judge: &mut SortingJudge, output: &str) -> (InteractiveReport, String) {
+        let mut to_solution = Vec::new();
+        let report = interact(judge, &mut to_solution, &mut output.as_bytes());
+        (report, String::from_utf8(to_solution).unwrap())
+    }
+
+    #[test]
+    fn sorting_judge_answers_queries() {
+        let mut judge = SortingJudge::new("CAB", 3);
+        assert_eq!(judge.start(), "3 3");
+        assert_eq!(judge.respond("? A B\n"), Response::Reply("<".to_string()));
+        assert_eq!(judge.respond("? A C"), Response::Reply(">".to_string()));
+        assert_eq!(judge.queries(), 2);
+    }
+
+    #[test]
+    fn sorting_judge_accepts_sorted_answer() {
+        let mut judge = SortingJudge::new("CAB", 3);
+        let response = judge.respond("! CAB");
+        assert_eq!(
+            response,
+            Response::Verdict(Verdict::Accepted, String::new())
+        );
+    }
+
+    #[test]
+    fn sorting_judge_rejects_wrong_answer() {
+        let mut judge = SortingJudge::new("CAB", 3);
+        let response = judge.respond("! ABC");
+        assert!(matches!(
+            response,
+            Response::Verdict(Verdict::WrongAnswer, _)
+        ));
+    }
+
+    #[test]
+    fn sorting_judge_rejects_invalid_queries() {
+        let mut judge = SortingJudge::new("CAB", 10);
+        for line in ["? A A", "? A D", "? AB C", "? A", "A B", ""] {
+            let response = judge.respond(line);
+            assert!(matches!(
+                response,
+                Response::Verdict(Verdict::WrongAnswer, _)
+            ));
+        }
+    }
+
+    #[test]
+    fn sorting_judge_enforces_query_limit() {
+        let mut judge = SortingJudge::new("CAB", 1);
+        assert_eq!(judge.respond("? A B"), Response::Reply("<".to_string()));
+        let response = judge.respond("? A B");
+        let expected = Response::Verdict(Verdict::WrongAnswer, "more than 1 queries".to_string());
+        assert_eq!(response, expected);
+    }
+
+    #[test]
+    fn sorting_judge_random_is_permutation() {
+        let mut rng = XorShift64::new(7);
+        let judge = SortingJudge::random(26, 100, &mut rng);
+        let mut letters = judge.order.clone();
+        letters.sort_unstable();
+        assert_eq!(letters, ('A'..='Z').collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn interact_transcript() {
+        let mut judge = SortingJudge::new("BAC", 7);
+        let (report, sent) = interact_str(&mut judge, "? A B\n? A C\n! BAC\n");
+        assert_eq!(sent, "3 7\n>\n<\n");
+        let expected = InteractiveReport {
+            verdict: Verdict::Accepted,
+            queries: 2,
+            detail: String::new(),
+        };
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn interact_missing_answer() {
+        let mut judge = SortingJudge::new("BAC", 7);
+        let (report, _) = interact_str(&mut judge, "? A B\n");
+        assert_eq!(report.verdict, Verdict::RuntimeError);
+        assert_eq!(report.queries, 1);
+    }
+
+    #[test]
+    fn stdio_interactor_transcript() {
+        let mut output = Vec::new();
+        let mut io = StdioInteractor::new("3 7\n>\n<\n".as_bytes(), &mut output);
+        assert_eq!(io.read_header().unwrap(), (3, 7));
+        assert_eq!(io.ask('A', 'B').unwrap(), Ordering::Greater);
+        assert_eq!(io.ask('A', 'C').unwrap(), Ordering::Less);
+        io.answer(&['B', 'A', 'C']).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "? A B\n? A C\n! BAC\n");
+    }
+
+    #[test]
+    fn invalid_stdio_interactor_response() {
+        let mut output = Vec::new();
+        let mut io = StdioInteractor::new("3 7\n=\n".as_bytes(), &mut output);
+        io.read_header().unwrap();
+        assert!(matches!(io.ask('A', 'B'), Err(PuzzleError::Judge(_))));
+    }
+
+    #[test]
+    fn mock_interactor_verdict() {
+        let mut io = MockInteractor::new(SortingJudge::new("BAC", 7));
+        assert_eq!(io.ask('A', 'B').unwrap(), Ordering::Greater);
+        assert_eq!(io.verdict(), None);
+        io.answer(&['B', 'A', 'C']).unwrap();
+        assert_eq!(io.verdict(), Some(Verdict::Accepted));
+        assert_eq!(io.queries(), 1);
+    }
+
+    #[test]
+    fn mock_interactor_query_limit() {
+        let mut io = MockInteractor::new(SortingJudge::new("BAC", 1));
+        io.ask('A', 'B').unwrap();
+        assert!(matches!(io.ask('A', 'C'), Err(PuzzleError::Judge(_))));
+        assert_eq!(io.verdict(), Some(Verdict::WrongAnswer));
+    }
+
+    #[test]
+    fn invalid_spawn_missing_program() {
+        let mut judge = SortingJudge::new("BAC", 7);
+        let result = spawn(Path::new("./no_such_program"), &mut judge);
+        assert!(matches!(result, Err(PuzzleError::Io { .. })));
+    }
+
+    /// Sends a shell script as the start line, and accepts any output line.
+    struct ScriptJudge {
+        script: &'static str,
+        time_limit: time::Duration,
+    }
+
+    impl InteractiveJudge for ScriptJudge {
+        fn start(&mut self) -> String {
+            self.script.to_string()
+        }
+
+        fn respond(&mut self, _line: &str) -> Response {
+            Response::Verdict(Verdict::Accepted, String::new())
+        }
+
+        fn queries(&self) -> usize {
+            0
+        }
+
+        fn time_limit(&self) -> time::Duration {
+            self.time_limit
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn spawn_drains_large_stderr() {
+        let mut judge = ScriptJudge {
+            script: "head -c 1000000 /dev/zero >&2; echo done",
+            time_limit: time::Duration::from_secs(10),
+        };
+        let report = spawn(Path::new("sh"), &mut judge).unwrap();
+        assert_eq!(report.verdict, Verdict::Accepted);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn spawn_kills_at_time_limit() {
+        // the shell waits for its next command, and never answers
+        let mut judge = ScriptJudge {
+            script: "true",
+            time_limit: time::Duration::from_millis(100),
+        };
+        let report = spawn(Path::new("sh"), &mut judge).unwrap();
+        assert_eq!(report.verdict, Verdict::TimeLimitExceeded);
+    }
+}
@@ -0,0 +1,314 @@
+//! AtCoder solutions in Rust by TBali
+//!
+//! Shared harness for the puzzle solutions in `src/puzzles`: input reading, output formatting,
+//! the `Puzzle` trait and the timing wrapper around parsing and solving.
+
+use scanner::Scanner;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::path;
+use std::time;
+
+extern crate self as atcoder_rust;
+
+pub mod cli;
+pub mod combination;
+pub mod convolution;
+pub mod dsu;
+pub mod error;
+pub mod fenwick;
+pub mod fps;
+pub mod graph;
+pub mod interactive;
+pub mod math;
+pub mod memory;
+pub mod modint;
+pub mod puzzles;
+mod range;
+pub mod rng;
+pub mod scanner;
+pub mod segtree;
+pub mod verify;
+
+pub use atcoder_rust_derive::PuzzleInput;
+pub use error::PuzzleError;
+
+/// AtCoder's usual time limit, unless a puzzle overrides `Puzzle::time_limit()`.
+pub const DEFAULT_TIME_LIMIT: time::Duration = time::Duration::from_secs(2);
+/// Memory limit in bytes of the older contests, unless a puzzle overrides `Puzzle::memory_limit()`.
+pub const DEFAULT_MEMORY_LIMIT: usize = 256 << 20;
+
+/// A non-interactive AtCoder task: parses the input tokens, then solves the parsed input.
+pub trait Puzzle {
+    type Input;
+    type Output: fmt::Display;
+
+    /// The puzzle id, also used as the prefix of the example input files in `./input/`.
+    fn id(&self) -> &'static str;
+    fn title(&self) -> &'static str;
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<Self::Input, PuzzleError>;
+    fn solve(&self, input: &Self::Input) -> Result<Self::Output, PuzzleError>;
+
+    fn time_limit(&self) -> time::Duration {
+        DEFAULT_TIME_LIMIT
+    }
+
+    /// The memory limit in bytes.
+    fn memory_limit(&self) -> usize {
+        DEFAULT_MEMORY_LIMIT
+    }
+}
+
+/// Puzzle input read field by field from a `Scanner`, usually with `#[derive(PuzzleInput)]`.
+pub trait PuzzleInput: Sized {
+    fn read_from<R: BufRead>(sc: &mut Scanner<R>) -> Result<Self, PuzzleError>;
+}
+
+/// Object-safe view of a `Puzzle`, so that puzzles with different input and output types
+/// can be stored together in the registry.
+pub trait DynPuzzle {
+    fn id(&self) -> &'static str;
+    fn title(&self) -> &'static str;
+    fn time_limit(&self) -> time::Duration;
+    fn memory_limit(&self) -> usize;
+    /// Parses the whole input and solves the puzzle, returning the formatted output.
+    fn execute(&self, reader: &mut dyn BufRead) -> Result<String, PuzzleError>;
+}
+
+impl<P: Puzzle> DynPuzzle for P {
+    fn id(&self) -> &'static str {
+        Puzzle::id(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Puzzle::title(self)
+    }
+
+    fn time_limit(&self) -> time::Duration {
+        Puzzle::time_limit(self)
+    }
+
+    fn memory_limit(&self) -> usize {
+        Puzzle::memory_limit(self)
+    }
+
+    fn execute(&self, reader: &mut dyn BufRead) -> Result<String, PuzzleError> {
+        let mut sc = Scanner::new(reader);
+        let input = self.parse(&mut sc)?;
+        sc.finish()?;
+        let result = self.solve(&input)?;
+        Ok(result.to_string())
+    }
+}
+
+/// Parses and solves the puzzle, printing the result to `stdout`, and the elapsed time and peak
+/// heap usage to `stderr`.
+///
+/// With a `safety_factor`, the elapsed time is scaled by it, and flagged if over the time limit,
+/// as is the peak heap usage if over the memory limit.
+pub fn run(
+    puzzle: &dyn DynPuzzle,
+    reader: &mut dyn BufRead,
+    safety_factor: Option<f64>,
+) -> Result<(), PuzzleError> {
+    let now = time::Instant::now();
+    let (result, peak_memory) = memory::measure(|| puzzle.execute(reader));
+    let elapsed = now.elapsed();
+    let result = result?;
+    let over_limit =
+        safety_factor.and_then(|f| verify::check_limits(puzzle, elapsed, Some(peak_memory), f));
+    let flag = match over_limit {
+        Some(verdict) => format!(", {}", verdict),
+        None => String::new(),
+    };
+    eprintln!(
+        "=== {} [time: {} ms, memory: {}{}] : {}",
+        puzzle.id(),
+        elapsed.as_millis(),
+        memory::format_memory(peak_memory),
+        flag,
+        puzzle.title()
+    );
+    println!("{}", result);
+    Ok(())
+}
+
+/// Directory of the example inputs `PUZZLE_ID_N.txt` and expected outputs `PUZZLE_ID_N.out`.
+pub const INPUT_DIR: &str = "./input";
+
+/// Path of example case `test_case` of the puzzle, with the given file extension.
+pub fn case_path(puzzle_id: &str, test_case: &str, extension: &str) -> String {
+    format!("{}/{}_{}.{}", INPUT_DIR, puzzle_id, test_case, extension)
+}
+
+pub fn open_file(puzzle_id: &str, test_case: &str) -> Result<io::BufReader<fs::File>, PuzzleError> {
+    let input_path = case_path(puzzle_id, test_case, "txt");
+    let file = fs::File::open(path::Path::new(&input_path)).map_err(|source| PuzzleError::Io {
+        path: input_path.clone(),
+        source,
+    })?;
+    Ok(io::BufReader::new(file))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PuzzleOutput {
+    pub line: String,
+}
+
+impl fmt::Display for PuzzleOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.line)
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_file_existing() {
+        let mut sc = Scanner::new(open_file("abc042_b", "1").unwrap());
+        assert_eq!(sc.next::<usize>("n"), Ok(3));
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file("abc042_b", "0");
+        assert!(matches!(
+            reader.unwrap_err(),
+            PuzzleError::Io { path, .. } if path == "./input/abc042_b_0.txt"
+        ));
+    }
+
+    #[derive(Debug, PartialEq, PuzzleInput)]
+    struct DerivedInput {
+        #[line(0)]
+        n: usize,
+        #[range(-5..=5)]
+        k: i32,
+        #[line(1)]
+        #[len = "n"]
+        #[range(1..=9)]
+        a: Vec<u8>,
+        #[len = "n - 1"]
+        s: Vec<String>,
+    }
+
+    fn read_derived(input: &str) -> Result<DerivedInput, PuzzleError> {
+        DerivedInput::read_from(&mut Scanner::new(input.as_bytes()))
+    }
+
+    #[test]
+    fn derive_puzzle_input() {
+        let input = read_derived("3 -5\n1 2 9\nab\ncd\n").unwrap();
+        let expected = DerivedInput {
+            n: 3,
+            k: -5,
+            a: vec![1, 2, 9],
+            s: vec!["ab".to_string(), "cd".to_string()],
+        };
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn invalid_derive_missing_on_line() {
+        let input = read_derived("3\n-5\n1 2 9\nab\ncd\n");
+        assert_eq!(input.unwrap_err().to_string(), "line 1: missing `k`");
+    }
+
+    #[test]
+    fn invalid_derive_integer() {
+        let input = read_derived("x -5\n");
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: Some(token), .. } if field == "n" && token == "x"
+        ));
+    }
+
+    #[test]
+    fn invalid_derive_line_leftover() {
+        let input = read_derived("3 -5 7\n1 2 9\nab\ncd\n");
+        assert_eq!(
+            input.unwrap_err().to_string(),
+            "line 1: `a` must be at the start of line 2, found `7`"
+        );
+    }
+
+    #[test]
+    fn invalid_derive_list_on_line() {
+        let input = read_derived("3 -5\n1 2\n9\nab\ncd\n");
+        assert_eq!(input.unwrap_err().to_string(), "line 2: missing `a`");
+    }
+
+    #[test]
+    fn invalid_derive_range() {
+        let input = read_derived("3 6\n1 2 9\nab\ncd\n");
+        let expected = PuzzleError::Constraint {
+            field: "k".to_string(),
+            value: "6".to_string(),
+            range: "-5..=5".to_string(),
+        };
+        assert_eq!(input.unwrap_err(), expected);
+    }
+
+    #[test]
+    fn invalid_derive_list_range() {
+        let input = read_derived("3 0\n1 0 9\nab\ncd\n");
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Constraint { field, value, .. } if field == "a" && value == "0"
+        ));
+    }
+
+    #[derive(Debug, PartialEq, PuzzleInput)]
+    struct BoundedInput {
+        #[line(0)]
+        #[range(2..=100)]
+        h: usize,
+        #[range(1..h)]
+        a: usize,
+    }
+
+    #[test]
+    fn derive_range_refers_to_earlier_field() {
+        let input = BoundedInput::read_from(&mut Scanner::new("5 4".as_bytes()));
+        assert_eq!(input, Ok(BoundedInput { h: 5, a: 4 }));
+    }
+
+    #[test]
+    fn invalid_derive_range_refers_to_earlier_field() {
+        let input = BoundedInput::read_from(&mut Scanner::new("5 5".as_bytes()));
+        assert_eq!(
+            input.unwrap_err().to_string(),
+            "`a` = 5 violates the constraint 1..5"
+        );
+    }
+
+    #[test]
+    fn output_display() {
+        let output = PuzzleOutput {
+            line: "6 test".to_string(),
+        };
+        assert_eq!(output.to_string(), "6 test");
+    }
+
+    #[test]
+    fn execute_formats_output() {
+        let puzzle = puzzles::find("practice_a").unwrap();
+        let result = puzzle.execute(&mut "1\n2 3\ntest\n".as_bytes());
+        assert_eq!(result, Ok("6 test".to_string()));
+    }
+
+    #[test]
+    fn invalid_execute_trailing_input() {
+        let puzzle = puzzles::find("practice_a").unwrap();
+        let result = puzzle.execute(&mut "1\n2 3\ntest\nextra\n".as_bytes());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 4: expected end of input, found `extra`"
+        );
+    }
+}
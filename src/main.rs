@@ -1,8 +1,23 @@
-fn main() {
-    let msg = r#"AtCoder solutions in Rust by TBali
+use atcoder_rust::memory::CountingAllocator;
+use atcoder_rust::{cli, PuzzleError};
+use std::env;
+use std::process::ExitCode;
 
-see individual puzzle solutions in `src/bin`
-run a solution with example input from file with `cargo run --release --bin PUZZLE_ID
-"#;
-    println!("{}", msg);
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn try_main() -> Result<(), PuzzleError> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = cli::parse_args(&args)?;
+    cli::execute(&command)
+}
+
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
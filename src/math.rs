@@ -0,0 +1,621 @@
+//! Number theory: primes, factorization, gcd and the Chinese remainder theorem.
+//!
+//! `Sieve` answers many queries on small numbers from a smallest-prime-factor table. The free
+//! functions work on any `u64`, with deterministic Miller–Rabin and Pollard's rho.
+
+/// Linear sieve of Euler up to `n`, with the smallest prime factor of every number.
+#[derive(Clone, Debug)]
+pub struct Sieve {
+    spf: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl Sieve {
+    /// O(n) time, each number is crossed out once, by its smallest prime factor.
+    pub fn new(n: usize) -> Self {
+        let mut spf = vec![0u32; n + 1];
+        let mut primes = Vec::new();
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+            }
+            for &p in &primes {
+                if p > spf[i] || i * p as usize > n {
+                    break;
+                }
+                spf[i * p as usize] = p;
+            }
+        }
+        Sieve { spf, primes }
+    }
+
+    /// The largest number of the table.
+    pub fn max_n(&self) -> usize {
+        self.spf.len() - 1
+    }
+
+    /// The primes up to `max_n()`, in increasing order.
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    pub fn is_prime(&self, x: usize) -> bool {
+        x >= 2 && self.spf[x] as usize == x
+    }
+
+    /// The smallest prime factor of `x >= 2`.
+    pub fn smallest_prime_factor(&self, x: usize) -> usize {
+        assert!(x >= 2, "{} has no prime factor", x);
+        self.spf[x] as usize
+    }
+
+    /// The prime factors of `x >= 1` with their exponents, in increasing order, in O(log x).
+    pub fn factorize(&self, mut x: usize) -> Vec<(usize, u32)> {
+        let mut factors: Vec<(usize, u32)> = Vec::new();
+        while x > 1 {
+            let p = self.spf[x] as usize;
+            match factors.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => factors.push((p, 1)),
+            }
+            x /= p;
+        }
+        factors
+    }
+
+    /// The divisors of `x >= 1`, in increasing order.
+    pub fn divisors(&self, x: usize) -> Vec<usize> {
+        let factors = self
+            .factorize(x)
+            .into_iter()
+            .map(|(p, e)| (p as u64, e))
+            .collect::<Vec<_>>();
+        divisors_from_factors(&factors)
+            .into_iter()
+            .map(|d| d as usize)
+            .collect()
+    }
+
+    /// Euler's totient of `x >= 1`.
+    pub fn euler_phi(&self, x: usize) -> usize {
+        self.factorize(x)
+            .into_iter()
+            .fold(x, |phi, (p, _)| phi / p * (p - 1))
+    }
+
+    /// The Möbius function of `x >= 1`.
+    pub fn mobius(&self, x: usize) -> i8 {
+        let factors = self.factorize(x);
+        if factors.iter().any(|&(_, e)| e > 1) {
+            0
+        } else if factors.len() % 2 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+fn divisors_from_factors(factors: &[(u64, u32)]) -> Vec<u64> {
+    let mut divisors = vec![1];
+    for &(p, e) in factors {
+        let count = divisors.len();
+        let mut power = 1;
+        for _ in 0..e {
+            power *= p;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, `lcm(0, x) = 0`; panics on overflow in debug builds.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// `(g, x, y)` with `a x + b y = g = gcd(a, b)`, `g >= 0`.
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `m >= 1` in `0..m`, if `a` and `m` are coprime; `m` need not be
+/// a prime.
+pub fn inv_mod(a: i64, m: i64) -> Option<i64> {
+    assert!(m >= 1, "modulus {} must be positive", m);
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves `x = r_i (mod m_i)` for all the `(r_i, m_i)` pairs, the moduli need not be coprime.
+///
+/// Returns `(x, lcm)` with `x` in `0..lcm`, `Some((0, 1))` for no equations, `None` if they are
+/// inconsistent. The lcm of the moduli must fit in `i64`.
+pub fn crt(equations: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut r0, mut m0) = (0i128, 1i128);
+    for &(r, m) in equations {
+        assert!(m >= 1, "modulus {} must be positive", m);
+        let (r1, m1) = ((r as i128).rem_euclid(m as i128), m as i128);
+        let (g, p, _) = ext_gcd(m0 as i64, m1 as i64);
+        let (g, p) = (g as i128, p as i128);
+        if (r1 - r0) % g != 0 {
+            return None;
+        }
+        // x = r0 + m0 t with m0 t = r1 - r0 (mod m1), p = (m0 / g)^-1 (mod m1 / g)
+        let m1_g = m1 / g;
+        let t = ((r1 - r0) / g % m1_g * p).rem_euclid(m1_g);
+        r0 += m0 * t;
+        m0 *= m1_g;
+        r0 = r0.rem_euclid(m0);
+    }
+    Some((r0 as i64, m0 as i64))
+}
+
+/// Garner's algorithm: the `x` with `x = residues[i] (mod moduli[i])`, reduced modulo `modulus`,
+/// without big integers. The moduli must be pairwise coprime.
+pub fn garner(residues: &[u64], moduli: &[u64], modulus: u64) -> u64 {
+    assert_eq!(residues.len(), moduli.len(), "one residue per modulus");
+    // x = c_0 + c_1 m_0 + c_2 m_0 m_1 + ..., with the partial sums kept modulo each later modulus
+    let mut all_moduli = moduli.to_vec();
+    all_moduli.push(modulus);
+    let mut products = all_moduli.iter().map(|&m| 1 % m).collect::<Vec<_>>();
+    let mut sums = vec![0u64; all_moduli.len()];
+    for (i, (&r, &m)) in residues.iter().zip(moduli).enumerate() {
+        let inv = inv_mod_u64(products[i], m).expect("pairwise coprime moduli");
+        let c = mul_mod(add_mod(r % m, m - sums[i], m), inv, m);
+        for j in i + 1..all_moduli.len() {
+            let mj = all_moduli[j];
+            sums[j] = add_mod(sums[j], mul_mod(c, products[j], mj), mj);
+            products[j] = mul_mod(products[j], m, mj);
+        }
+    }
+    sums[moduli.len()]
+}
+
+/// `sum(floor((a i + b) / m))` for `i` in `0..n`, in O(log m); `m >= 1`, any sign of `a` and `b`.
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    assert!(m >= 1, "modulus {} must be positive", m);
+    let (n, m) = (n as i128, m as i128);
+    let (mut a, mut b) = (a as i128, b as i128);
+    let mut result = 0i128;
+    // make 0 <= a, b < m
+    if !(0..m).contains(&a) {
+        let q = a.div_euclid(m);
+        result += n * (n - 1) / 2 * q;
+        a -= q * m;
+    }
+    if !(0..m).contains(&b) {
+        let q = b.div_euclid(m);
+        result += n * q;
+        b -= q * m;
+    }
+    (result + floor_sum_unsigned(n, m, a, b)) as i64
+}
+
+fn floor_sum_unsigned(mut n: i128, mut m: i128, mut a: i128, mut b: i128) -> i128 {
+    let mut result = 0;
+    loop {
+        if a >= m {
+            result += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            result += n * (b / m);
+            b %= m;
+        }
+        let y_max = a * n + b;
+        if y_max < m {
+            return result;
+        }
+        // count the lattice points under the line from the other axis
+        (n, b) = (y_max / m, y_max % m);
+        (m, a) = (a, m);
+    }
+}
+
+/// `inv_mod()` for any `u64` modulus.
+fn inv_mod_u64(a: u64, m: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (a as i128 % m as i128, m as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+    }
+    (old_r == 1).then(|| old_x.rem_euclid(m as i128) as u64)
+}
+
+/// `a + b` modulo `m`, for `a, b <= m`.
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 + b as u128) % m as u128) as u64
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Deterministic Miller–Rabin for all `u64`, with the seven bases of Jim Sinclair.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    [2, 325, 9375, 28178, 450775, 9780504, 1795265022]
+        .iter()
+        .map(|&a| a % n)
+        .filter(|&a| a != 0)
+        .all(|a| {
+            let mut x = pow_mod(a, d, n);
+            if x == 1 || x == n - 1 {
+                return true;
+            }
+            for _ in 1..s {
+                x = mul_mod(x, x, n);
+                if x == n - 1 {
+                    return true;
+                }
+            }
+            false
+        })
+}
+
+/// A non-trivial factor of the odd composite `n`, by Pollard's rho with Brent's cycle detection
+/// and batched gcds.
+fn pollard_rho(n: u64) -> u64 {
+    const BATCH: u64 = 128;
+    for c in 1.. {
+        let f = |x: u64| (mul_mod(x, x, n) + c) % n;
+        let (mut x, mut y, mut ys) = (0, 2, 2);
+        let (mut g, mut q, mut r) = (1, 1, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // the batch overshot, redo it step by step
+            g = 1;
+            while g == 1 {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// The prime factors of `n >= 1` with their exponents, in increasing order.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    assert!(n >= 1, "0 has no factorization");
+    let mut primes = Vec::new();
+    let mut rest = n;
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while rest % p == 0 {
+            primes.push(p);
+            rest /= p;
+        }
+    }
+    let mut stack = vec![rest];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+            continue;
+        }
+        let d = pollard_rho(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// The divisors of `n >= 1`, in increasing order.
+pub fn divisors(n: u64) -> Vec<u64> {
+    divisors_from_factors(&factorize(n))
+}
+
+/// Euler's totient of `n >= 1`.
+pub fn euler_phi(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .fold(n, |phi, (p, _)| phi / p * (p - 1))
+}
+
+/// The Möbius function of `n >= 1`.
+pub fn mobius(n: u64) -> i8 {
+    let factors = factorize(n);
+    if factors.iter().any(|&(_, e)| e > 1) {
+        0
+    } else if factors.len() % 2 == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift64;
+
+    fn is_prime_naive(n: u64) -> bool {
+        n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+    }
+
+    fn factorize_naive(mut n: u64) -> Vec<(u64, u32)> {
+        let mut factors = Vec::new();
+        let mut d = 2;
+        while d * d <= n {
+            let mut e = 0;
+            while n % d == 0 {
+                n /= d;
+                e += 1;
+            }
+            if e > 0 {
+                factors.push((d, e));
+            }
+            d += 1;
+        }
+        if n > 1 {
+            factors.push((n, 1));
+        }
+        factors
+    }
+
+    #[test]
+    fn sieve_vs_trial_division() {
+        let sieve = Sieve::new(10_000);
+        assert_eq!(sieve.max_n(), 10_000);
+        assert_eq!(sieve.primes().len(), 1229);
+        for x in 0..=10_000 {
+            assert_eq!(sieve.is_prime(x), is_prime_naive(x as u64), "{}", x);
+        }
+        for x in 1..=10_000 {
+            let expected = factorize_naive(x as u64)
+                .into_iter()
+                .map(|(p, e)| (p as usize, e))
+                .collect::<Vec<_>>();
+            assert_eq!(sieve.factorize(x), expected, "{}", x);
+        }
+        assert_eq!(sieve.smallest_prime_factor(9991), 97);
+    }
+
+    #[test]
+    fn divisors_phi_mobius_vs_brute_force() {
+        let sieve = Sieve::new(1000);
+        for x in 1..=1000usize {
+            let expected = (1..=x).filter(|d| x % d == 0).collect::<Vec<_>>();
+            assert_eq!(sieve.divisors(x), expected);
+            let coprime = (1..=x).filter(|&k| gcd(k as u64, x as u64) == 1).count();
+            assert_eq!(sieve.euler_phi(x), coprime, "{}", x);
+            // sum of mu(d) over the divisors is [x == 1]
+            let mu_sum = expected
+                .iter()
+                .map(|&d| sieve.mobius(d) as i32)
+                .sum::<i32>();
+            assert_eq!(mu_sum, (x == 1) as i32);
+            assert_eq!(
+                divisors(x as u64),
+                expected.iter().map(|&d| d as u64).collect::<Vec<_>>()
+            );
+            assert_eq!(euler_phi(x as u64), coprime as u64);
+            assert_eq!(mobius(x as u64), sieve.mobius(x));
+        }
+        assert_eq!(sieve.mobius(30), -1);
+        assert_eq!(sieve.mobius(12), 0);
+    }
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+    }
+
+    #[test]
+    fn ext_gcd_bezout() {
+        let mut rng = XorShift64::new(21);
+        for _ in 0..1000 {
+            let a = rng.range_i64(-1_000_000_000, 1_000_000_000);
+            let b = rng.range_i64(-1_000_000_000, 1_000_000_000);
+            let (g, x, y) = ext_gcd(a, b);
+            assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+        }
+        assert_eq!(ext_gcd(0, 0), (0, 1, 0));
+    }
+
+    #[test]
+    fn inv_mod_vs_brute_force() {
+        for m in 1..60i64 {
+            for a in -60..60 {
+                let expected = (0..m).find(|x| (a * x).rem_euclid(m) == 1 % m);
+                assert_eq!(inv_mod(a, m), expected, "{} mod {}", a, m);
+            }
+        }
+    }
+
+    #[test]
+    fn crt_vs_brute_force() {
+        for m1 in 1..13i64 {
+            for m2 in 1..13 {
+                for r1 in 0..m1 {
+                    for r2 in -2..m2 {
+                        let l = lcm(m1 as u64, m2 as u64) as i64;
+                        let expected = (0..l)
+                            .find(|x| (x - r1) % m1 == 0 && (x - r2).rem_euclid(m2) == 0)
+                            .map(|x| (x, l));
+                        assert_eq!(crt(&[(r1, m1), (r2, m2)]), expected);
+                    }
+                }
+            }
+        }
+        assert_eq!(crt(&[]), Some((0, 1)));
+        let (x, l) = crt(&[(1, 1_000_000_007), (2, 998_244_353)]).unwrap();
+        assert_eq!(l, 1_000_000_007 * 998_244_353);
+        assert_eq!((x % 1_000_000_007, x % 998_244_353), (1, 2));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+    }
+
+    #[test]
+    fn garner_vs_crt() {
+        let moduli = [3, 5, 7, 11];
+        for x in 0..1155u64 {
+            let residues = moduli.map(|m| x % m);
+            assert_eq!(garner(&residues, &moduli, 1_000_000_007), x);
+            assert_eq!(garner(&residues, &moduli, 100), x % 100);
+        }
+        // beyond u64: x = 2^64 + 5 reduced modulo 1e9+7
+        let primes = [754_974_721, 167_772_161, 469_762_049];
+        let x = (1u128 << 64) + 5;
+        let residues = primes.map(|p| (x % p as u128) as u64);
+        assert_eq!(
+            garner(&residues, &primes, 1_000_000_007),
+            (x % 1_000_000_007) as u64
+        );
+        // moduli above i64::MAX
+        let primes = [18_446_744_073_709_551_557, 18_446_744_073_709_551_533];
+        let x = (1u128 << 127) + 12_345;
+        let residues = primes.map(|p| (x % p as u128) as u64);
+        assert_eq!(
+            garner(&residues, &primes, 1_000_000_007),
+            (x % 1_000_000_007) as u64
+        );
+        assert_eq!(garner(&residues, &primes, primes[0]), residues[0]);
+        assert_eq!(
+            garner(&residues, &primes, u64::MAX),
+            (x % u64::MAX as u128) as u64
+        );
+    }
+
+    #[test]
+    fn floor_sum_vs_naive() {
+        for n in 0..15i64 {
+            for m in 1..10 {
+                for a in -12..12 {
+                    for b in -12..12 {
+                        let expected = (0..n).map(|i| (a * i + b).div_euclid(m)).sum::<i64>();
+                        assert_eq!(floor_sum(n, m, a, b), expected, "{} {} {} {}", n, m, a, b);
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            floor_sum(1_000_000_000, 1_000_000_000, 999_999_999, 999_999_999),
+            499_999_999_500_000_000
+        );
+    }
+
+    #[test]
+    fn is_prime_vs_trial_division() {
+        for n in 0..20_000 {
+            assert_eq!(is_prime(n), is_prime_naive(n), "{}", n);
+        }
+        // Carmichael numbers and strong pseudoprimes to several bases
+        for n in [561, 41041, 3_215_031_751, 3_825_123_056_546_413_051] {
+            assert!(!is_prime(n), "{}", n);
+        }
+        for n in [1_000_000_007, 998_244_353, 18_446_744_073_709_551_557] {
+            assert!(is_prime(n), "{}", n);
+        }
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn factorize_64_bit() {
+        let mut rng = XorShift64::new(22);
+        for _ in 0..200 {
+            let n = rng.next_u64() >> rng.below(64);
+            let n = n.max(1);
+            let factors = factorize(n);
+            assert!(factors.iter().all(|&(p, _)| is_prime(p)), "{}", n);
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            let product = factors.iter().fold(1u64, |acc, &(p, e)| acc * p.pow(e));
+            assert_eq!(product, n);
+        }
+        assert_eq!(factorize(1), []);
+        assert_eq!(
+            factorize(4_294_967_291 * 4_294_967_279),
+            [(4_294_967_279, 1), (4_294_967_291, 1)]
+        );
+        assert_eq!(factorize(1 << 63), [(2, 63)]);
+        assert_eq!(
+            divisors(1_000_000_007 * 3),
+            [1, 3, 1_000_000_007, 3_000_000_021]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Heap usage measurement with a counting global allocator.
+//!
+//! Only heap allocations are counted, so the peak is a lower bound of the peak RSS reported by
+//! the judge, which also includes the stack, the binary and the allocator overhead.
+//!
+//! The library does not install the allocator, a binary opts in with
+//! `#[global_allocator] static GLOBAL: CountingAllocator = CountingAllocator;`, otherwise the
+//! measured peak is zero. The counters are process-wide: allocations of other threads while a
+//! measurement runs, like concurrent tests, are counted too.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// The system allocator, counting the currently allocated and the peak bytes.
+pub struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// The counters are process-wide, so measurements must not overlap.
+static MEASURE_LOCK: Mutex<()> = Mutex::new(());
+
+#[cfg(test)]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn add(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(allocated, Ordering::Relaxed);
+}
+
+fn sub(size: usize) {
+    ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        sub(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            add(new_size);
+            sub(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// The currently allocated heap bytes.
+pub fn allocated() -> usize {
+    ALLOCATED.load(Ordering::Relaxed)
+}
+
+/// Runs `f`, returning its result and the peak heap bytes allocated while it ran, above the
+/// usage at the start.
+///
+/// Measurements are serialized, so `f` must not call `measure()` itself. Allocations by other
+/// threads are not excluded, so the result can exceed the usage of `f` alone.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let _guard = MEASURE_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let baseline = allocated();
+    PEAK.store(baseline, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.load(Ordering::Relaxed).saturating_sub(baseline);
+    (result, peak)
+}
+
+/// Formats a byte count in KiB, rounded up, as shown by the judge.
+pub fn format_memory(bytes: usize) -> String {
+    format!("{} KiB", (bytes + 1023) / 1024)
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_counts_peak_not_current() {
+        let (len, peak) = measure(|| {
+            let big = vec![1u8; 10 << 20];
+            big.len()
+        });
+        assert_eq!(len, 10 << 20);
+        assert!(peak >= 10 << 20);
+    }
+
+    #[test]
+    fn measure_counts_realloc() {
+        let (_, peak) = measure(|| {
+            let mut v = Vec::<u64>::with_capacity(1);
+            v.extend(0..(1 << 20));
+            v.len()
+        });
+        assert!(peak >= 8 << 20);
+    }
+
+    #[test]
+    fn format_memory_rounds_up() {
+        assert_eq!(format_memory(0), "0 KiB");
+        assert_eq!(format_memory(1), "1 KiB");
+        assert_eq!(format_memory(2048), "2 KiB");
+    }
+}
@@ -0,0 +1,568 @@
+//! Integers modulo `M`, with the arithmetic operators.
+//!
+//! `ModInt<M>` takes the modulus as a const generic, so values of different moduli cannot be
+//! mixed, and every operation reduces its result. Division and `inv()` assume `M` is a prime.
+//! `DynModInt` carries its modulus at run time, for moduli read from the input.
+//!
+//! ```
+//! use atcoder_rust::modint::Mint1e9_7;
+//!
+//! let x = Mint1e9_7::new(1_000_000_006);
+//! assert_eq!(x + Mint1e9_7::new(2), Mint1e9_7::new(1));
+//! assert_eq!((x / Mint1e9_7::new(2)).value(), 500_000_003);
+//! ```
+
+use crate::scanner::FromToken;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str;
+use std::str::FromStr;
+
+/// The usual modulus of AtCoder tasks.
+pub const MODULUS_1E9_7: u32 = 1_000_000_007;
+/// The NTT-friendly modulus, `119 * 2^23 + 1`.
+pub const MODULUS_998: u32 = 998_244_353;
+
+pub type Mint1e9_7 = ModInt<MODULUS_1E9_7>;
+pub type Mint998 = ModInt<MODULUS_998>;
+
+/// An integer modulo `M`, always kept in `0..M`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u32> {
+    value: u32,
+}
+
+impl<const M: u32> ModInt<M> {
+    pub const MODULUS: u32 = M;
+
+    pub fn new(value: u64) -> Self {
+        ModInt {
+            value: (value % M as u64) as u32,
+        }
+    }
+
+    pub const fn zero() -> Self {
+        ModInt { value: 0 }
+    }
+
+    pub const fn one() -> Self {
+        ModInt { value: 1 % M }
+    }
+
+    /// The representative in `0..M`.
+    pub fn value(self) -> u32 {
+        self.value
+    }
+
+    /// `self^exp` by binary exponentiation.
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse by Fermat's little theorem, `M` must be a prime.
+    pub fn inv(self) -> Self {
+        assert!(self.value != 0, "0 has no inverse modulo {}", M);
+        self.pow(M as u64 - 2)
+    }
+
+    /// A square root by Tonelli–Shanks, the smaller of the two, or `None` for a non-residue.
+    /// `M` must be a prime.
+    pub fn sqrt(self) -> Option<Self> {
+        if self.value == 0 || M == 2 {
+            return Some(self);
+        }
+        let half = (M as u64 - 1) / 2;
+        if self.pow(half) != Self::one() {
+            return None;
+        }
+        // M - 1 = q * 2^s with q odd, z is any non-residue
+        let s = (M - 1).trailing_zeros();
+        let q = (M as u64 - 1) >> s;
+        let z = (2..M)
+            .map(Self::from)
+            .find(|z| z.pow(half) != Self::one())
+            .expect("a non-residue exists modulo an odd prime");
+        let (mut m, mut c, mut t, mut r) = (s, z.pow(q), self.pow(q), self.pow((q + 1) / 2));
+        while t != Self::one() {
+            let mut i = 0;
+            let mut t_pow = t;
+            while t_pow != Self::one() {
+                t_pow *= t_pow;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+        Some(if r.value <= (-r).value { r } else { -r })
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),+) => {
+        $(
+            impl<const M: u32> From<$t> for ModInt<M> {
+                fn from(value: $t) -> Self {
+                    ModInt {
+                        value: (value as u128 % M as u128) as u32,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),+) => {
+        $(
+            impl<const M: u32> From<$t> for ModInt<M> {
+                fn from(value: $t) -> Self {
+                    ModInt {
+                        value: (value as i128).rem_euclid(M as i128) as u32,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl<const M: u32> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let sum = self.value as u64 + rhs.value as u64;
+        ModInt {
+            value: if sum >= M as u64 { sum - M as u64 } else { sum } as u32,
+        }
+    }
+}
+
+impl<const M: u32> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        ModInt {
+            value: if self.value >= rhs.value {
+                self.value - rhs.value
+            } else {
+                self.value + (M - rhs.value)
+            },
+        }
+    }
+}
+
+impl<const M: u32> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        ModInt {
+            value: (self.value as u64 * rhs.value as u64 % M as u64) as u32,
+        }
+    }
+}
+
+impl<const M: u32> Div for ModInt<M> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<const M: u32> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+/// The `*Assign` operators from the binary operators, `impl_assign_ops!([generics] Type)`.
+macro_rules! impl_assign_ops {
+    ([$($gen:tt)*] $ty:ty) => {
+        impl<$($gen)*> AddAssign for $ty {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<$($gen)*> SubAssign for $ty {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<$($gen)*> MulAssign for $ty {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<$($gen)*> DivAssign for $ty {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+impl_assign_ops!([const M: u32] ModInt<M>);
+
+impl<const M: u32> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a, const M: u32> Sum<&'a Self> for ModInt<M> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const M: u32> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<'a, const M: u32> Product<&'a Self> for ModInt<M> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<const M: u32> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<const M: u32> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, M)
+    }
+}
+
+/// Parses any integer in the `i128` range, reduced modulo `M`.
+impl<const M: u32> FromStr for ModInt<M> {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, ParseIntError> {
+        s.parse::<i128>().map(Self::from)
+    }
+}
+
+impl<const M: u32> FromToken for ModInt<M> {
+    const EXPECTED: &'static str = "integer";
+
+    fn from_token(token: &[u8]) -> Option<Self> {
+        str::from_utf8(token).ok()?.parse().ok()
+    }
+}
+
+/// An integer modulo a modulus chosen at run time.
+///
+/// Both operands of an operator must have the same modulus.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl DynModInt {
+    /// `modulus` must be in `1..2^32`, so that products fit in `u64`.
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert!(
+            (1..1 << 32).contains(&modulus),
+            "modulus {} out of range",
+            modulus
+        );
+        DynModInt {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// Reduces a signed value.
+    pub fn from_i64(value: i64, modulus: u64) -> Self {
+        DynModInt::new(value.rem_euclid(modulus as i64) as u64, modulus)
+    }
+
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = DynModInt::new(1, self.modulus);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse by the extended Euclidean algorithm, so the modulus need not
+    /// be a prime, only coprime to the value.
+    pub fn inv(self) -> Self {
+        let (mut a, mut b) = (self.value as i64, self.modulus as i64);
+        let (mut x, mut y) = (1i64, 0i64);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        assert!(
+            a == 1,
+            "{} has no inverse modulo {}",
+            self.value,
+            self.modulus
+        );
+        DynModInt::from_i64(x, self.modulus)
+    }
+
+    fn check_modulus(self, rhs: Self) {
+        debug_assert_eq!(self.modulus, rhs.modulus, "mixed moduli");
+    }
+}
+
+impl Add for DynModInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.check_modulus(rhs);
+        DynModInt::new(self.value + rhs.value, self.modulus)
+    }
+}
+
+impl Sub for DynModInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.check_modulus(rhs);
+        DynModInt::new(self.value + self.modulus - rhs.value, self.modulus)
+    }
+}
+
+impl Mul for DynModInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.check_modulus(rhs);
+        DynModInt::new(self.value * rhs.value, self.modulus)
+    }
+}
+
+impl Div for DynModInt {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl Neg for DynModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DynModInt::new(self.modulus - self.value, self.modulus)
+    }
+}
+
+impl_assign_ops!([] DynModInt);
+
+impl fmt::Display for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Debug for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::Scanner;
+
+    type Mint7 = ModInt<7>;
+
+    #[test]
+    fn new_reduces() {
+        assert_eq!(Mint7::new(15).value(), 1);
+        assert_eq!(
+            Mint1e9_7::new(u64::MAX).value(),
+            (u64::MAX % 1_000_000_007) as u32
+        );
+        assert_eq!(Mint7::from(-1i64).value(), 6);
+        assert_eq!(
+            Mint7::from(i128::MIN).value(),
+            i128::MIN.rem_euclid(7) as u32
+        );
+        assert_eq!(Mint7::from(10usize).value(), 3);
+        assert_eq!(ModInt::<1>::one().value(), 0);
+    }
+
+    #[test]
+    fn operators_match_integers() {
+        for a in -10i64..10 {
+            for b in -10i64..10 {
+                let (x, y) = (Mint7::from(a), Mint7::from(b));
+                assert_eq!(x + y, Mint7::from(a + b));
+                assert_eq!(x - y, Mint7::from(a - b));
+                assert_eq!(x * y, Mint7::from(a * b));
+                assert_eq!(-x, Mint7::from(-a));
+                if b % 7 != 0 {
+                    assert_eq!(x / y * y, x);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn add_near_modulus() {
+        let max = Mint998::new(MODULUS_998 as u64 - 1);
+        assert_eq!(max + max, Mint998::new(MODULUS_998 as u64 - 2));
+        assert_eq!(Mint998::zero() - max, Mint998::one());
+        assert_eq!(max * max, Mint998::one());
+    }
+
+    #[test]
+    fn assign_operators() {
+        let mut x = Mint7::new(3);
+        x += Mint7::new(5);
+        assert_eq!(x.value(), 1);
+        x -= Mint7::new(2);
+        assert_eq!(x.value(), 6);
+        x *= Mint7::new(4);
+        assert_eq!(x.value(), 3);
+        x /= Mint7::new(3);
+        assert_eq!(x.value(), 1);
+    }
+
+    #[test]
+    fn pow_and_inv() {
+        assert_eq!(Mint1e9_7::new(2).pow(10).value(), 1024);
+        assert_eq!(Mint1e9_7::new(0).pow(0), Mint1e9_7::one());
+        let x = Mint1e9_7::new(123_456_789);
+        assert_eq!(x * x.inv(), Mint1e9_7::one());
+        assert_eq!(x.pow(MODULUS_1E9_7 as u64 - 1), Mint1e9_7::one());
+        assert_eq!(Mint1e9_7::new(2).inv().value(), 500_000_004);
+    }
+
+    #[test]
+    fn sqrt_residues() {
+        for x in 0..100u64 {
+            let x = Mint998::new(x);
+            if let Some(r) = x.sqrt() {
+                assert_eq!(r * r, x);
+                assert!(r.value() <= MODULUS_998 / 2);
+            }
+        }
+        assert_eq!(Mint998::new(4).sqrt(), Some(Mint998::new(2)));
+        // 3 is a primitive root, so not a square
+        assert_eq!(Mint998::new(3).sqrt(), None);
+        let squares = (0..7).filter(|&x| Mint7::new(x).sqrt().is_some()).count();
+        assert_eq!(squares, 4);
+        assert_eq!(ModInt::<2>::one().sqrt(), Some(ModInt::one()));
+    }
+
+    #[test]
+    #[should_panic(expected = "no inverse")]
+    fn invalid_inv_of_zero() {
+        Mint7::zero().inv();
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let values = (1..=10).map(Mint1e9_7::new).collect::<Vec<_>>();
+        assert_eq!(values.iter().sum::<Mint1e9_7>().value(), 55);
+        assert_eq!(values.into_iter().product::<Mint1e9_7>().value(), 3_628_800);
+        assert_eq!(std::iter::empty::<Mint7>().product::<Mint7>(), Mint7::one());
+    }
+
+    #[test]
+    fn display_and_parse() {
+        let x = "-1".parse::<Mint1e9_7>().unwrap();
+        assert_eq!(x.to_string(), "1000000006");
+        assert_eq!(format!("{:?}", Mint7::new(3)), "3 (mod 7)");
+        assert_eq!(
+            "100000000000000000000".parse::<Mint7>().unwrap(),
+            Mint7::new(2)
+        );
+        assert!("x".parse::<Mint7>().is_err());
+    }
+
+    #[test]
+    fn scanner_reads_modint() {
+        let mut sc = Scanner::new("12 x".as_bytes());
+        assert_eq!(sc.next::<Mint7>("a"), Ok(Mint7::new(5)));
+        assert_eq!(
+            sc.next::<Mint7>("b").unwrap_err().to_string(),
+            "line 1: `b` must be integer, found `x`"
+        );
+    }
+
+    #[test]
+    fn dyn_modint_operators() {
+        let m = 10;
+        let x = DynModInt::new(7, m);
+        let y = DynModInt::from_i64(-3, m);
+        assert_eq!(y.value(), 7);
+        assert_eq!((x + y).value(), 4);
+        assert_eq!((x - DynModInt::new(9, m)).value(), 8);
+        assert_eq!((x * y).value(), 9);
+        assert_eq!((-x).value(), 3);
+        assert_eq!(x.pow(3).value(), 3);
+        let mut z = x;
+        z *= DynModInt::new(3, m);
+        z -= DynModInt::new(1, m);
+        assert_eq!(z.to_string(), "0");
+        assert_eq!(z.modulus(), 10);
+    }
+
+    #[test]
+    fn dyn_modint_inv_composite_modulus() {
+        // 3 * 7 = 21 = 1 (mod 10)
+        assert_eq!(DynModInt::new(3, 10).inv().value(), 7);
+        assert_eq!((DynModInt::new(1, 10) / DynModInt::new(9, 10)).value(), 9);
+        let p = DynModInt::new(5, MODULUS_1E9_7 as u64);
+        assert_eq!((p * p.inv()).value(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "no inverse")]
+    fn invalid_dyn_modint_inv_not_coprime() {
+        DynModInt::new(4, 10).inv();
+    }
+}
@@ -0,0 +1,161 @@
+//! [link](https://atcoder.jp/contests/abc042/tasks/abc042_a)
+
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "abc042_a";
+const TITLE: &str = "AtCoder Beginner Contest 042 : Task A - Iroha and Haiku (ABC Edition)";
+
+type ItemType = u32;
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    #[range(1..=10)]
+    a: ItemType,
+    #[range(1..=10)]
+    b: ItemType,
+    #[range(1..=10)]
+    c: ItemType,
+}
+
+pub struct Abc042A;
+
+impl Puzzle for Abc042A {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        ParsedInput::read_from(sc)
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        let line = match (input.a, input.b, input.c) {
+            (5, 5, 7) | (5, 7, 5) | (7, 5, 5) => "YES",
+            _ => "NO",
+        };
+        Ok(PuzzleOutput {
+            line: line.to_string(),
+        })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("5 5 7".as_bytes());
+        let input = Abc042A.parse(&mut sc).unwrap();
+        let result = Abc042A.solve(&input).unwrap();
+        assert_eq!(result.line, "YES");
+    }
+
+    #[test]
+    fn example2() {
+        let mut sc = Scanner::new("7 7 5".as_bytes());
+        let input = Abc042A.parse(&mut sc).unwrap();
+        let result = Abc042A.solve(&input).unwrap();
+        assert_eq!(result.line, "NO");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = Abc042A.execute(&mut "5 5 7\n1".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_a() {
+        let mut sc = Scanner::new("".as_bytes());
+        let input = Abc042A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "a"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_b() {
+        let mut sc = Scanner::new("5".as_bytes());
+        let input = Abc042A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "b"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_c() {
+        let mut sc = Scanner::new("5 7".as_bytes());
+        let input = Abc042A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "c"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_a() {
+        let mut sc = Scanner::new("a 5 7".as_bytes());
+        let input = Abc042A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "a" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_b() {
+        let mut sc = Scanner::new("5 b 7".as_bytes());
+        let input = Abc042A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "b" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_c() {
+        let mut sc = Scanner::new("5 7 c".as_bytes());
+        let input = Abc042A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "c" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_range_c() {
+        let mut sc = Scanner::new("5 5 11".as_bytes());
+        let input = Abc042A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Constraint { field, .. } if field == "c"
+        ));
+    }
+}
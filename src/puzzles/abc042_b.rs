@@ -0,0 +1,145 @@
+//! [link](https://atcoder.jp/contests/abc042/tasks/abc042_b)
+
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "abc042_b";
+const TITLE: &str = "AtCoder Beginner Contest 042 : Task B - Iroha Loves Strings (ABC Edition)";
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    #[range(1..=100)]
+    n: usize,
+    #[range(1..=100)]
+    l: usize,
+    #[len = "n"]
+    s: Vec<String>,
+}
+
+pub struct Abc042B;
+
+impl Puzzle for Abc042B {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        let input = ParsedInput::read_from(sc)?;
+        if let Some(s) = input.s.iter().find(|s| s.len() != input.l) {
+            Err(PuzzleError::Constraint {
+                field: "s".to_string(),
+                value: s.clone(),
+                range: format!("length {}", input.l),
+            })?
+        }
+        Ok(input)
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        let mut fragments = input.s.to_vec();
+        fragments.sort();
+        let line = fragments.join("");
+        Ok(PuzzleOutput { line })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("3 3\ndxx\naxx\ncxx".as_bytes());
+        let input = Abc042B.parse(&mut sc).unwrap();
+        let result = Abc042B.solve(&input).unwrap();
+        assert_eq!(result.line, "axxcxxdxx");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = Abc042B.execute(&mut "3 3\ndxx\naxx\ncxx\nbxx".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_s() {
+        let mut sc = Scanner::new("3 3\ndxx".as_bytes());
+        let input = Abc042B.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "s"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_n() {
+        let mut sc = Scanner::new("".as_bytes());
+        let input = Abc042B.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "n"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_l() {
+        let mut sc = Scanner::new("3".as_bytes());
+        let input = Abc042B.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "l"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_n() {
+        let mut sc = Scanner::new("a 3".as_bytes());
+        let input = Abc042B.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "n" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_l() {
+        let mut sc = Scanner::new("3 l\ndxx\naxx\ncxx".as_bytes());
+        let input = Abc042B.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "l" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_length_s() {
+        let mut sc = Scanner::new("3 3\ndxx\nax\ncxx".as_bytes());
+        let input = Abc042B.parse(&mut sc);
+        assert_eq!(
+            input.unwrap_err().to_string(),
+            "`s` = ax violates the constraint length 3"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! [link](https://atcoder.jp/contests/abc042/tasks/arc058_a)
+//!
+//! also [link](https://atcoder.jp/contests/arc058/tasks/arc058_a)
+
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "abc042_c";
+const TITLE: &str =
+    "AtCoder Beginner Contest 042 / Regular Contest 058 : Task C - Iroha's Obsession";
+
+type ItemType = u32;
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    #[range(1..10000)]
+    n: ItemType,
+    #[range(1..10)]
+    k: usize,
+    #[line(1)]
+    #[len = "k"]
+    #[range(0..=9)]
+    d: Vec<u8>,
+}
+
+pub struct Abc042C;
+
+impl Puzzle for Abc042C {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        ParsedInput::read_from(sc)
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        let mut digits = [false; 10];
+        for &digit in &input.d {
+            digits[digit as usize] = true;
+        }
+        let mut ans = input.n;
+        loop {
+            if ans
+                .to_string()
+                .chars()
+                .map(|x| x.to_digit(10).unwrap() as u8)
+                .all(|x| !digits[x as usize])
+            {
+                break;
+            }
+            ans += 1;
+        }
+        let line = ans.to_string();
+        Ok(PuzzleOutput { line })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("1000 8\n1 3 4 5 6 7 8 9".as_bytes());
+        let input = Abc042C.parse(&mut sc).unwrap();
+        let result = Abc042C.solve(&input).unwrap();
+        assert_eq!(result.line, "2000");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = Abc042C.execute(&mut "1000 8\n1 3 4 5 6 7 8 9\n1".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_d() {
+        let mut sc = Scanner::new("1000 8\n1 3 4 5 6 7 8".as_bytes());
+        let input = Abc042C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "d"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_n() {
+        let mut sc = Scanner::new("\n1 3 4 5 6 7 8 9".as_bytes());
+        let input = Abc042C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "n"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_k() {
+        let mut sc = Scanner::new("1000\n1 3 4 5 6 7 8 9".as_bytes());
+        let input = Abc042C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "k"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_n() {
+        let mut sc = Scanner::new("n 8\n1 3 4 5 6 7 8 9".as_bytes());
+        let input = Abc042C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "n" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_k() {
+        let mut sc = Scanner::new("1000 k\n1 3 4 5 6 7 8 9".as_bytes());
+        let input = Abc042C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "k" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_list_d() {
+        let mut sc = Scanner::new("1000 8\n1 a 4 5 6 7 8 9".as_bytes());
+        let input = Abc042C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "d" && expected == "list of positive integers"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_decimal_digit_list_d() {
+        let mut sc = Scanner::new("1000 8\n1 31 4 5 6 7 8 9".as_bytes());
+        let input = Abc042C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Constraint { field, .. } if field == "d"
+        ));
+    }
+}
@@ -0,0 +1,231 @@
+//! [link](https://atcoder.jp/contests/abc042/tasks/arc058_b)
+//!
+//! also: [link](https://atcoder.jp/contests/arc058/tasks/arc058_b)
+
+use crate::combination::Combination;
+use crate::modint::{Mint1e9_7, MODULUS_1E9_7};
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "abc042_d";
+const TITLE: &str =
+    "AtCoder Beginner Contest 042 / Regular Contest 058 : Task D - Iroha and a Grid";
+
+type ItemType = usize;
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    #[range(1..=100_000)]
+    h: ItemType,
+    #[range(1..=100_000)]
+    w: ItemType,
+    #[range(1..h)]
+    a: ItemType,
+    #[range(1..w)]
+    b: ItemType,
+}
+
+pub struct Abc042D;
+
+impl Puzzle for Abc042D {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        ParsedInput::read_from(sc)
+    }
+
+    /// Every path crosses the row `h - a - 1` just above the forbidden area once, leaving it
+    /// downwards at some column `x >= b`, so the count is a sum of `W - B` products of binomials.
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        let comb = Combination::<MODULUS_1E9_7>::new(input.h + input.w);
+        let top = input.h - input.a - 1;
+        let bottom = input.a - 1;
+        let count = (input.b..input.w)
+            .map(|x| comb.binom(top + x, x) * comb.binom(bottom + input.w - 1 - x, bottom))
+            .sum::<Mint1e9_7>();
+        Ok(PuzzleOutput {
+            line: count.to_string(),
+        })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("2 3 1 1".as_bytes());
+        let input = Abc042D.parse(&mut sc).unwrap();
+        let result = Abc042D.solve(&input).unwrap();
+        assert_eq!(result.line, "2");
+    }
+
+    #[test]
+    fn example2() {
+        let mut sc = Scanner::new("10 7 3 4".as_bytes());
+        let input = Abc042D.parse(&mut sc).unwrap();
+        let result = Abc042D.solve(&input).unwrap();
+        assert_eq!(result.line, "3570");
+    }
+
+    #[test]
+    fn example3() {
+        let mut sc = Scanner::new("100000 100000 99999 99999".as_bytes());
+        let input = Abc042D.parse(&mut sc).unwrap();
+        let result = Abc042D.solve(&input).unwrap();
+        assert_eq!(result.line, "1");
+    }
+
+    #[test]
+    fn example4() {
+        let mut sc = Scanner::new("100000 100000 44444 55555".as_bytes());
+        let input = Abc042D.parse(&mut sc).unwrap();
+        let result = Abc042D.solve(&input).unwrap();
+        assert_eq!(result.line, "738162020");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = Abc042D.execute(&mut "2 3 1 1\n1".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_h() {
+        let mut sc = Scanner::new("".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "h"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_w() {
+        let mut sc = Scanner::new("2".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "w"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_a() {
+        let mut sc = Scanner::new("2 3".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "a"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_b() {
+        let mut sc = Scanner::new("2 3 1".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "b"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_h() {
+        let mut sc = Scanner::new("h 3 1 1".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "h" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_w() {
+        let mut sc = Scanner::new("2 w 1 1".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "w" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_a() {
+        let mut sc = Scanner::new("2 3 a 1".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "a" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_b() {
+        let mut sc = Scanner::new("2 3 1 b".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "b" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_range_h() {
+        let mut sc = Scanner::new("100001 3 1 1".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Constraint { field, .. } if field == "h"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_range_a_not_less_than_h() {
+        let mut sc = Scanner::new("2 3 2 1".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        let expected = PuzzleError::Constraint {
+            field: "a".to_string(),
+            value: "2".to_string(),
+            range: "1..2".to_string(),
+        };
+        assert_eq!(input.unwrap_err(), expected);
+    }
+
+    #[test]
+    fn invalid_input_range_b_not_less_than_w() {
+        let mut sc = Scanner::new("2 3 1 3".as_bytes());
+        let input = Abc042D.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Constraint { field, value, .. } if field == "b" && value == "3"
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+//! [link](https://atcoder.jp/contests/abc043/tasks/abc043_a)
+
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "abc043_a";
+const TITLE: &str = "AtCoder Beginner Contest 043 : Task A - Children and Candies (ABC Edit)";
+
+type ItemType = u32;
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    #[range(1..=100)]
+    n: ItemType,
+}
+
+pub struct Abc043A;
+
+impl Puzzle for Abc043A {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        ParsedInput::read_from(sc)
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        let ans = (input.n * (input.n + 1)) / 2;
+        Ok(PuzzleOutput {
+            line: ans.to_string(),
+        })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("3".as_bytes());
+        let input = Abc043A.parse(&mut sc).unwrap();
+        let result = Abc043A.solve(&input).unwrap();
+        assert_eq!(result.line, "6");
+    }
+
+    #[test]
+    fn example2() {
+        let mut sc = Scanner::new("10".as_bytes());
+        let input = Abc043A.parse(&mut sc).unwrap();
+        let result = Abc043A.solve(&input).unwrap();
+        assert_eq!(result.line, "55");
+    }
+
+    #[test]
+    fn example3() {
+        let mut sc = Scanner::new("1".as_bytes());
+        let input = Abc043A.parse(&mut sc).unwrap();
+        let result = Abc043A.solve(&input).unwrap();
+        assert_eq!(result.line, "1");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = Abc043A.execute(&mut "3\n1".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_n() {
+        let mut sc = Scanner::new("".as_bytes());
+        let input = Abc043A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "n"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_n() {
+        let mut sc = Scanner::new("a 5 7".as_bytes());
+        let input = Abc043A.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "n" && expected == "positive integer"
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+//! [link](https://atcoder.jp/contests/abc043/tasks/abc043_b)
+
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "abc043_b";
+const TITLE: &str = "AtCoder Beginner Contest 043 : Task B - Unhappy Hacking (ABC Edit)";
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    s: String,
+}
+
+pub struct Abc043B;
+
+impl Puzzle for Abc043B {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        ParsedInput::read_from(sc)
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        let mut line = String::new();
+        for c in input.s.chars() {
+            match c {
+                '0' | '1' => line.push(c),
+                'B' => {
+                    if !line.is_empty() {
+                        line.truncate(line.len() - 1)
+                    }
+                }
+                _ => Err(PuzzleError::Constraint {
+                    field: "s".to_string(),
+                    value: format!("{:?}", c),
+                    range: "'0', '1' or 'B'".to_string(),
+                })?,
+            }
+        }
+        Ok(PuzzleOutput { line })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("01B0".as_bytes());
+        let input = Abc043B.parse(&mut sc).unwrap();
+        let result = Abc043B.solve(&input).unwrap();
+        assert_eq!(result.line, "00");
+    }
+
+    #[test]
+    fn example2() {
+        let mut sc = Scanner::new("0BB1".as_bytes());
+        let input = Abc043B.parse(&mut sc).unwrap();
+        let result = Abc043B.solve(&input).unwrap();
+        assert_eq!(result.line, "1");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = Abc043B.execute(&mut "0BB1\n1".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_s() {
+        let mut sc = Scanner::new("".as_bytes());
+        let input = Abc043B.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "s"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_only_01b_allowed() {
+        let mut sc = Scanner::new("0BAB1".as_bytes());
+        let input = Abc043B.parse(&mut sc).unwrap();
+        let result = Abc043B.solve(&input);
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Constraint { field, .. } if field == "s"
+        ));
+    }
+}
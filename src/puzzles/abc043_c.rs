@@ -0,0 +1,151 @@
+//! [link](https://atcoder.jp/contests/abc043/tasks/arc059_a)
+//!
+//! also [link](https://atcoder.jp/contests/arc059/tasks/arc059_a)
+
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "abc043_c";
+const TITLE: &str = "AtCoder Beginner Contest 043 / Regular Contest 059 : Task C - Be Together";
+
+type ItemType = i32;
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    #[range(1..=100)]
+    n: usize,
+    #[line(1)]
+    #[len = "n"]
+    #[range(-100..=100)]
+    a: Vec<ItemType>,
+}
+
+pub struct Abc043C;
+
+impl Puzzle for Abc043C {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        ParsedInput::read_from(sc)
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        let mut best_cost = ItemType::MAX;
+        for c in -100..=100 {
+            let cost = input.a.iter().map(|&x| (x - c) * (x - c)).sum::<ItemType>();
+            if cost < best_cost {
+                best_cost = cost;
+            }
+        }
+        Ok(PuzzleOutput {
+            line: best_cost.to_string(),
+        })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("2\n4 8".as_bytes());
+        let input = Abc043C.parse(&mut sc).unwrap();
+        let result = Abc043C.solve(&input).unwrap();
+        assert_eq!(result.line, "8");
+    }
+
+    #[test]
+    fn example2() {
+        let mut sc = Scanner::new("3\n1 1 3".as_bytes());
+        let input = Abc043C.parse(&mut sc).unwrap();
+        let result = Abc043C.solve(&input).unwrap();
+        assert_eq!(result.line, "3");
+    }
+
+    #[test]
+    fn example3() {
+        let mut sc = Scanner::new("3\n4 2 5".as_bytes());
+        let input = Abc043C.parse(&mut sc).unwrap();
+        let result = Abc043C.solve(&input).unwrap();
+        assert_eq!(result.line, "5");
+    }
+
+    #[test]
+    fn example4() {
+        let mut sc = Scanner::new("4\n-100 -100 -100 -100".as_bytes());
+        let input = Abc043C.parse(&mut sc).unwrap();
+        let result = Abc043C.solve(&input).unwrap();
+        assert_eq!(result.line, "0");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = Abc043C.execute(&mut "2\n4 8 1".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_a() {
+        let mut sc = Scanner::new("2".as_bytes());
+        let input = Abc043C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "a"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_n() {
+        let mut sc = Scanner::new("a\n4 8".as_bytes());
+        let input = Abc043C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "n" && expected == "positive integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_list_a() {
+        let mut sc = Scanner::new("2\n4 a".as_bytes());
+        let input = Abc043C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. }
+                if field == "a" && expected == "list of integers"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_range_list_a() {
+        let mut sc = Scanner::new("2\n4 101".as_bytes());
+        let input = Abc043C.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Constraint { field, value, .. } if field == "a" && value == "101"
+        ));
+    }
+}
@@ -0,0 +1,52 @@
+//! Registry of the non-interactive puzzle solutions.
+
+use crate::DynPuzzle;
+
+pub mod abc042_a;
+pub mod abc042_b;
+pub mod abc042_c;
+pub mod abc042_d;
+pub mod abc043_a;
+pub mod abc043_b;
+pub mod abc043_c;
+pub mod practice_a;
+
+/// All puzzles runnable by the `atcoder-rust` dispatcher, in contest order.
+pub const PUZZLES: &[&dyn DynPuzzle] = &[
+    &practice_a::PracticeA,
+    &abc042_a::Abc042A,
+    &abc042_b::Abc042B,
+    &abc042_c::Abc042C,
+    &abc042_d::Abc042D,
+    &abc043_a::Abc043A,
+    &abc043_b::Abc043B,
+    &abc043_c::Abc043C,
+];
+
+pub fn find(puzzle_id: &str) -> Option<&'static dyn DynPuzzle> {
+    PUZZLES.iter().copied().find(|p| p.id() == puzzle_id)
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_existing() {
+        let puzzle = find("abc042_d").unwrap();
+        assert_eq!(puzzle.id(), "abc042_d");
+    }
+
+    #[test]
+    fn find_missing() {
+        assert!(find("abc999_z").is_none());
+    }
+
+    #[test]
+    fn ids_are_unique() {
+        for (i, p) in PUZZLES.iter().enumerate() {
+            assert!(PUZZLES[(i + 1)..].iter().all(|q| q.id() != p.id()));
+        }
+    }
+}
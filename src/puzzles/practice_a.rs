@@ -0,0 +1,157 @@
+//! [link](https://atcoder.jp/contests/practice/tasks/practice_1)
+
+use crate::scanner::Scanner;
+use crate::{Puzzle, PuzzleError, PuzzleInput, PuzzleOutput};
+use std::io::BufRead;
+
+const PUZZLE_ID: &str = "practice_a";
+const TITLE: &str = "practice contest, A - Welcome to AtCoder";
+
+type ItemType = i32;
+
+#[derive(Debug, PartialEq, PuzzleInput)]
+pub struct ParsedInput {
+    #[line(0)]
+    #[range(1..=1000)]
+    a: ItemType,
+    #[line(1)]
+    #[range(1..=1000)]
+    b: ItemType,
+    #[range(1..=1000)]
+    c: ItemType,
+    #[line(2)]
+    s: String,
+}
+
+pub struct PracticeA;
+
+impl Puzzle for PracticeA {
+    type Input = ParsedInput;
+    type Output = PuzzleOutput;
+
+    fn id(&self) -> &'static str {
+        PUZZLE_ID
+    }
+
+    fn title(&self) -> &'static str {
+        TITLE
+    }
+
+    fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<ParsedInput, PuzzleError> {
+        ParsedInput::read_from(sc)
+    }
+
+    fn solve(&self, input: &ParsedInput) -> Result<PuzzleOutput, PuzzleError> {
+        Ok(PuzzleOutput {
+            line: format!("{} {}", input.a + input.b + input.c, input.s),
+        })
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{open_file, DynPuzzle};
+
+    #[test]
+    fn example1() {
+        let mut sc = Scanner::new("1\n2 3\ntest".as_bytes());
+        let input = PracticeA.parse(&mut sc).unwrap();
+        let result = PracticeA.solve(&input).unwrap();
+        assert_eq!(result.line, "6 test");
+    }
+
+    #[test]
+    fn example2() {
+        let mut sc = Scanner::new("72\n128 256\nmyonmyon".as_bytes());
+        let input = PracticeA.parse(&mut sc).unwrap();
+        let result = PracticeA.solve(&input).unwrap();
+        assert_eq!(result.line, "456 myonmyon");
+    }
+
+    #[test]
+    fn invalid_missing_file() {
+        let reader = open_file(PUZZLE_ID, "0");
+        assert!(matches!(reader.unwrap_err(), PuzzleError::Io { .. }));
+    }
+
+    #[test]
+    fn invalid_input_trailing() {
+        let result = PracticeA.execute(&mut "1\n2 3\ntest\n1".as_bytes());
+        assert!(matches!(
+            result.unwrap_err(),
+            PuzzleError::Parse { expected, .. } if expected == "end of input"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_s() {
+        let mut sc = Scanner::new("1\n2 3".as_bytes());
+        let input = PracticeA.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "s"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_a() {
+        let mut sc = Scanner::new("a\n2 3\ntest".as_bytes());
+        let input = PracticeA.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. } if field == "a" && expected == "integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_b() {
+        let mut sc = Scanner::new("1\nb 3\ntest".as_bytes());
+        let input = PracticeA.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. } if field == "b" && expected == "integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_integer_c() {
+        let mut sc = Scanner::new("1\n2 c\ntest".as_bytes());
+        let input = PracticeA.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, expected, .. } if field == "c" && expected == "integer"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_b() {
+        let mut sc = Scanner::new("1\n\ntest".as_bytes());
+        let input = PracticeA.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "b"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_missing_c() {
+        let mut sc = Scanner::new("1\n2\ntest".as_bytes());
+        let input = PracticeA.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: None, .. } if field == "c"
+        ));
+    }
+
+    #[test]
+    fn invalid_input_line_structure() {
+        let mut sc = Scanner::new("1 2\n3\ntest".as_bytes());
+        let input = PracticeA.parse(&mut sc);
+        assert!(matches!(
+            input.unwrap_err(),
+            PuzzleError::Parse { field, token: Some(_), .. } if field == "b"
+        ));
+    }
+}
@@ -0,0 +1,50 @@
+//! Range bounds of the index-based data structures, normalized to half-open `start..end`.
+
+use std::ops::{Bound, RangeBounds};
+
+/// `[start, end)` of any range bounds, within `0..=n`.
+pub(crate) fn to_half_open(range: impl RangeBounds<usize>, n: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&l) => l,
+        Bound::Excluded(&l) => l + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&r) => r + 1,
+        Bound::Excluded(&r) => r,
+        Bound::Unbounded => n,
+    };
+    assert!(
+        start <= end && end <= n,
+        "range {}..{} out of 0..{}",
+        start,
+        end,
+        n
+    );
+    (start, end)
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_range_kinds() {
+        assert_eq!(to_half_open(1..3, 5), (1, 3));
+        assert_eq!(to_half_open(1..=3, 5), (1, 4));
+        assert_eq!(to_half_open(..2, 5), (0, 2));
+        assert_eq!(to_half_open(2.., 5), (2, 5));
+        assert_eq!(to_half_open(.., 0), (0, 0));
+        assert_eq!(
+            to_half_open((Bound::Excluded(1), Bound::Unbounded), 5),
+            (2, 5)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range 4..6 out of 0..5")]
+    fn invalid_range_beyond_end() {
+        to_half_open(4..=5, 5);
+    }
+}
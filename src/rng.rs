@@ -0,0 +1,125 @@
+//! Small xorshift pseudo-random generator for the local judges and randomized tests.
+//!
+//! Not for cryptography; the point is reproducible sequences from a seed, without dependencies.
+
+use std::time;
+
+/// The `xorshift64*` generator.
+#[derive(Clone, Debug)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    pub fn new(seed: u64) -> Self {
+        const MIX: u64 = 0x9E37_79B9_7F4A_7C15;
+        // a zero state would stay zero forever
+        let state = match seed ^ MIX {
+            0 => MIX,
+            state => state,
+        };
+        XorShift64 { state }
+    }
+
+    /// Seeded from the current time.
+    pub fn from_time() -> Self {
+        let nanos = time::SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        XorShift64::new(nanos)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a number in `0..n`, `n` must be positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Returns a number in the inclusive range `lo..=hi`.
+    pub fn range_i64(&mut self, lo: i64, hi: i64) -> i64 {
+        let span = hi.wrapping_sub(lo) as u64;
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        lo.wrapping_add(self.below(span + 1) as i64)
+    }
+
+    /// Fisher–Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// A random range `l..r` within `0..n`, possibly empty, for the randomized tests.
+#[cfg(test)]
+pub(crate) fn random_range(rng: &mut XorShift64, n: usize) -> (usize, usize) {
+    let a = rng.below(n as u64 + 1) as usize;
+    let b = rng.below(n as u64 + 1) as usize;
+    (a.min(b), a.max(b))
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = XorShift64::new(42);
+        let mut b = XorShift64::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn zero_seed_is_valid() {
+        let mut rng = XorShift64::new(0);
+        assert!((0..10).any(|_| rng.next_u64() != 0));
+    }
+
+    #[test]
+    fn seed_cancelling_the_mix_is_valid() {
+        let mut rng = XorShift64::new(0x9E37_79B9_7F4A_7C15);
+        assert!((0..10).all(|_| rng.next_u64() != 0));
+    }
+
+    #[test]
+    fn below_covers_range() {
+        let mut rng = XorShift64::new(1);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            seen[rng.below(7) as usize] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+    }
+
+    #[test]
+    fn range_i64_inclusive() {
+        let mut rng = XorShift64::new(2);
+        let values = (0..1000).map(|_| rng.range_i64(-2, 2)).collect::<Vec<_>>();
+        assert!(values.iter().all(|x| (-2..=2).contains(x)));
+        assert!(values.contains(&-2) && values.contains(&2));
+        rng.range_i64(i64::MIN, i64::MAX);
+    }
+
+    #[test]
+    fn shuffle_is_permutation() {
+        let mut rng = XorShift64::new(3);
+        let mut items = (0..50).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,666 @@
+//! Token-based input scanner over a buffered byte reader.
+//!
+//! Tokens are separated by ASCII whitespace, line breaks are not significant. Lines are read
+//! lazily into a single reused buffer, so large inputs do not allocate a `String` per line.
+//! Errors are `PuzzleError::Parse` with the line number, the offending token and the name of
+//! the field being read, e.g. "line 1: `h` must be positive integer, found `x`".
+//!
+//! The `input!` macro declares and reads several fields at once, in the style of `proconio`:
+//!
+//! ```
+//! use atcoder_rust::input;
+//! use atcoder_rust::scanner::{Chars, Scanner, Usize1};
+//!
+//! fn parse() -> Result<(), atcoder_rust::PuzzleError> {
+//!     let mut sc = Scanner::new("2 3\n1 2 3\n4 5 6\n.#\n#.\n2".as_bytes());
+//!     input! {
+//!         from sc,
+//!         h: usize,
+//!         w: usize,
+//!         a: [[u32; w]; h],
+//!         grid: [Chars; h],
+//!         mut k: Usize1,
+//!     }
+//!     k += 1;
+//!     assert_eq!(a[1], [4, 5, 6]);
+//!     assert_eq!(grid[0], ['.', '#']);
+//!     assert_eq!(k, 2);
+//!     Ok(())
+//! }
+//! # parse().unwrap();
+//! ```
+
+use crate::PuzzleError;
+use std::io::BufRead;
+use std::ops::Range;
+use std::str;
+
+/// Types that can be parsed from a single token.
+pub trait FromToken: Sized {
+    /// Description of a valid token, used in the error messages.
+    const EXPECTED: &'static str;
+
+    fn from_token(token: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_from_token {
+    ($expected:literal: $($t:ty),+) => {
+        $(
+            impl FromToken for $t {
+                const EXPECTED: &'static str = $expected;
+
+                fn from_token(token: &[u8]) -> Option<Self> {
+                    str::from_utf8(token).ok()?.parse().ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_from_token!("positive integer": u8, u16, u32, u64, u128, usize);
+impl_from_token!("integer": i8, i16, i32, i64, i128, isize);
+impl_from_token!("number": f32, f64);
+impl_from_token!("character": char);
+impl_from_token!("string": String);
+
+/// Tuples of `FromToken` types, read field by field with `Scanner::next_tuple()`.
+pub trait FromTokens: Sized {
+    /// `fields` must have one name per tuple item.
+    fn read<R: BufRead>(sc: &mut Scanner<R>, fields: &[&str]) -> Result<Self, PuzzleError>;
+}
+
+macro_rules! impl_from_tokens {
+    ($($t:ident $idx:tt),+) => {
+        impl<$($t: FromToken),+> FromTokens for ($($t,)+) {
+            fn read<R: BufRead>(sc: &mut Scanner<R>, fields: &[&str]) -> Result<Self, PuzzleError> {
+                Ok(($(sc.next::<$t>(fields[$idx])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_tokens!(A 0, B 1);
+impl_from_tokens!(A 0, B 1, C 2);
+impl_from_tokens!(A 0, B 1, C 2, D 3);
+impl_from_tokens!(A 0, B 1, C 2, D 3, E 4);
+impl_from_tokens!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Types that can be read by the `input!` macro, including the marker types below.
+pub trait Readable {
+    type Output;
+
+    fn read<R: BufRead>(sc: &mut Scanner<R>, field: &str) -> Result<Self::Output, PuzzleError>;
+
+    fn read_vec<R: BufRead>(
+        sc: &mut Scanner<R>,
+        n: usize,
+        field: &str,
+    ) -> Result<Vec<Self::Output>, PuzzleError> {
+        (0..n).map(|_| Self::read(sc, field)).collect()
+    }
+}
+
+impl<T: FromToken> Readable for T {
+    type Output = T;
+
+    fn read<R: BufRead>(sc: &mut Scanner<R>, field: &str) -> Result<T, PuzzleError> {
+        sc.next(field)
+    }
+
+    fn read_vec<R: BufRead>(
+        sc: &mut Scanner<R>,
+        n: usize,
+        field: &str,
+    ) -> Result<Vec<T>, PuzzleError> {
+        sc.next_vec(n, field)
+    }
+}
+
+/// Marker for a 1-based index, read as 0-based `usize`.
+pub enum Usize1 {}
+
+impl Readable for Usize1 {
+    type Output = usize;
+
+    fn read<R: BufRead>(sc: &mut Scanner<R>, field: &str) -> Result<usize, PuzzleError> {
+        sc.next_usize1(field)
+    }
+
+    fn read_vec<R: BufRead>(
+        sc: &mut Scanner<R>,
+        n: usize,
+        field: &str,
+    ) -> Result<Vec<usize>, PuzzleError> {
+        sc.next_vec_usize1(n, field)
+    }
+}
+
+/// Marker for a token read as `Vec<char>`; `[Chars; h]` reads a grid.
+pub enum Chars {}
+
+impl Readable for Chars {
+    type Output = Vec<char>;
+
+    fn read<R: BufRead>(sc: &mut Scanner<R>, field: &str) -> Result<Vec<char>, PuzzleError> {
+        sc.next_chars(field)
+    }
+}
+
+/// Marker for a token read as `Vec<u8>`; `[Bytes; h]` reads a grid.
+pub enum Bytes {}
+
+impl Readable for Bytes {
+    type Output = Vec<u8>;
+
+    fn read<R: BufRead>(sc: &mut Scanner<R>, field: &str) -> Result<Vec<u8>, PuzzleError> {
+        sc.next_bytes(field)
+    }
+}
+
+/// Declares and reads variables from a `Scanner`, returning early with the field-named error.
+///
+/// Each type is a single token tree: a `Readable` type (`usize`, `String`, `Usize1`, `Chars`,
+/// `Bytes`, ...) or a list `[T; len]`, which can be nested. Lengths may refer to fields read
+/// earlier. The enclosing function must return `Result<_, PuzzleError>`.
+#[macro_export]
+macro_rules! input {
+    (from $sc:expr $(,)?) => {};
+    (from $sc:expr, mut $var:ident : $t:tt $(, $($rest:tt)*)?) => {
+        let mut $var = $crate::input!(@value $sc, $t, stringify!($var));
+        $crate::input!(from $sc $(, $($rest)*)?);
+    };
+    (from $sc:expr, $var:ident : $t:tt $(, $($rest:tt)*)?) => {
+        let $var = $crate::input!(@value $sc, $t, stringify!($var));
+        $crate::input!(from $sc $(, $($rest)*)?);
+    };
+    (@value $sc:expr, [[$($inner:tt)*]; $n:expr], $field:expr) => {{
+        let mut list = Vec::with_capacity($n);
+        for _ in 0..$n {
+            list.push($crate::input!(@value $sc, [$($inner)*], $field));
+        }
+        list
+    }};
+    (@value $sc:expr, [$t:tt; $n:expr], $field:expr) => {
+        $sc.read_vec::<$t>($n, $field)?
+    };
+    (@value $sc:expr, $t:tt, $field:expr) => {
+        $sc.read::<$t>($field)?
+    };
+}
+
+pub struct Scanner<R> {
+    reader: R,
+    line: Vec<u8>,
+    pos: usize,
+    /// Count of lines read so far, the current line is `lines_read - 1` (0-based).
+    lines_read: usize,
+    /// If set, tokens are read only from the current line, see `goto_line()`.
+    locked: bool,
+}
+
+impl<R: BufRead> Scanner<R> {
+    pub fn new(reader: R) -> Self {
+        Scanner {
+            reader,
+            line: Vec::new(),
+            pos: 0,
+            lines_read: 0,
+            locked: false,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.line.len() && self.line[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    /// Reads the next line into the buffer, returns false at the end of the input.
+    fn read_line(&mut self) -> Result<bool, PuzzleError> {
+        self.line.clear();
+        self.pos = 0;
+        let count = self
+            .reader
+            .read_until(b'\n', &mut self.line)
+            .map_err(|source| PuzzleError::Io {
+                path: "input".to_string(),
+                source,
+            })?;
+        if count == 0 {
+            return Ok(false);
+        }
+        self.lines_read += 1;
+        Ok(true)
+    }
+
+    /// The 1-based number of the current line, used in the error messages.
+    pub fn line_number(&self) -> usize {
+        self.lines_read.max(1)
+    }
+
+    /// Returns the byte range of the next token in the line buffer, or `None` at the end of the
+    /// input (or of the locked line).
+    fn token(&mut self) -> Result<Option<Range<usize>>, PuzzleError> {
+        loop {
+            self.skip_whitespace();
+            if self.pos < self.line.len() {
+                break;
+            }
+            if self.locked || !self.read_line()? {
+                return Ok(None);
+            }
+        }
+        let start = self.pos;
+        while self.pos < self.line.len() && !self.line[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        Ok(Some(start..self.pos))
+    }
+
+    fn parse_error(&self, token: Option<Range<usize>>, field: &str, expected: &str) -> PuzzleError {
+        PuzzleError::Parse {
+            line: self.line_number(),
+            token: token.map(|range| String::from_utf8_lossy(&self.line[range]).into_owned()),
+            field: field.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn token_of(&mut self, field: &str, expected: &str) -> Result<Range<usize>, PuzzleError> {
+        match self.token()? {
+            Some(range) => Ok(range),
+            None => Err(self.parse_error(None, field, expected)),
+        }
+    }
+
+    /// Reads a token and parses it with `parse`, `expected` describes a valid token.
+    fn next_with<T>(
+        &mut self,
+        field: &str,
+        expected: &str,
+        parse: impl Fn(&[u8]) -> Option<T>,
+    ) -> Result<T, PuzzleError> {
+        let range = self.token_of(field, expected)?;
+        match parse(&self.line[range.clone()]) {
+            Some(x) => Ok(x),
+            None => Err(self.parse_error(Some(range), field, expected)),
+        }
+    }
+
+    pub fn next<T: FromToken>(&mut self, field: &str) -> Result<T, PuzzleError> {
+        self.next_with(field, T::EXPECTED, T::from_token)
+    }
+
+    pub fn next_vec<T: FromToken>(&mut self, n: usize, field: &str) -> Result<Vec<T>, PuzzleError> {
+        let expected = format!("list of {}s", T::EXPECTED);
+        (0..n)
+            .map(|_| self.next_with(field, &expected, T::from_token))
+            .collect()
+    }
+
+    /// Reads a tuple, e.g. `sc.next_tuple::<(usize, usize, i64)>(&["n", "m", "k"])`.
+    pub fn next_tuple<T: FromTokens>(&mut self, fields: &[&str]) -> Result<T, PuzzleError> {
+        T::read(self, fields)
+    }
+
+    pub fn next_chars(&mut self, field: &str) -> Result<Vec<char>, PuzzleError> {
+        self.next_with(field, "string", |token| {
+            Some(str::from_utf8(token).ok()?.chars().collect())
+        })
+    }
+
+    pub fn next_bytes(&mut self, field: &str) -> Result<Vec<u8>, PuzzleError> {
+        self.next_with(field, "string", |token| Some(token.to_vec()))
+    }
+
+    /// Reads `h` rows of characters, all of the same width.
+    pub fn next_grid(&mut self, h: usize, field: &str) -> Result<Vec<Vec<char>>, PuzzleError> {
+        let mut grid: Vec<Vec<char>> = Vec::with_capacity(h);
+        for _ in 0..h {
+            let row = self.next_chars(field)?;
+            if let Some(first) = grid.first() {
+                if row.len() != first.len() {
+                    return Err(PuzzleError::Parse {
+                        line: self.line_number(),
+                        token: Some(row.into_iter().collect()),
+                        field: field.to_string(),
+                        expected: format!("row of width {}", first.len()),
+                    });
+                }
+            }
+            grid.push(row);
+        }
+        Ok(grid)
+    }
+
+    /// Reads a 1-based index, returning it 0-based.
+    pub fn next_usize1(&mut self, field: &str) -> Result<usize, PuzzleError> {
+        self.next_with(field, "1-based index", parse_usize1)
+    }
+
+    /// Reads a list of 1-based indices, returning them 0-based.
+    pub fn next_vec_usize1(&mut self, n: usize, field: &str) -> Result<Vec<usize>, PuzzleError> {
+        (0..n)
+            .map(|_| self.next_with(field, "list of 1-based indices", parse_usize1))
+            .collect()
+    }
+
+    pub fn read<T: Readable>(&mut self, field: &str) -> Result<T::Output, PuzzleError> {
+        T::read(self, field)
+    }
+
+    pub fn read_vec<T: Readable>(
+        &mut self,
+        n: usize,
+        field: &str,
+    ) -> Result<Vec<T::Output>, PuzzleError> {
+        T::read_vec(self, n, field)
+    }
+
+    /// Moves to the start of the 0-based input `line`, and locks reading to that line.
+    ///
+    /// `field` is the first field expected on the line, it is used in the error messages.
+    /// The rest of the current line must have been consumed already.
+    pub fn goto_line(&mut self, line: usize, field: &str) -> Result<(), PuzzleError> {
+        let expected = format!("at the start of line {}", line + 1);
+        self.skip_whitespace();
+        if self.pos < self.line.len() {
+            let leftover = self.token()?;
+            Err(self.parse_error(leftover, field, &expected))?
+        }
+        if self.lines_read > line + 1 {
+            // already past the line, so the field is missing from it
+            Err(PuzzleError::Parse {
+                line: line + 1,
+                token: None,
+                field: field.to_string(),
+                expected,
+            })?
+        }
+        while self.lines_read < line + 1 {
+            if !self.read_line()? {
+                // report the missing fields on the expected line
+                self.lines_read = line + 1;
+                break;
+            }
+        }
+        self.locked = true;
+        Ok(())
+    }
+
+    /// Allows reading tokens again from the following lines, after a `goto_line()`.
+    pub fn unlock_line(&mut self) {
+        self.locked = false;
+    }
+
+    /// Checks that the whole input was consumed.
+    pub fn finish(&mut self) -> Result<(), PuzzleError> {
+        self.unlock_line();
+        match self.token()? {
+            None => Ok(()),
+            Some(range) => Err(self.parse_error(Some(range), "", "end of input")),
+        }
+    }
+}
+
+fn parse_usize1(token: &[u8]) -> Option<usize> {
+    usize::from_token(token)?.checked_sub(1)
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scanner(input: &str) -> Scanner<&[u8]> {
+        Scanner::new(input.as_bytes())
+    }
+
+    #[test]
+    fn next_across_lines() {
+        let mut sc = scanner("  12 -3\n\n  4.5 x\r\nabc\n");
+        assert_eq!(sc.next::<usize>("a"), Ok(12));
+        assert_eq!(sc.next::<i32>("b"), Ok(-3));
+        assert_eq!(sc.next::<f64>("c"), Ok(4.5));
+        assert_eq!(sc.next::<char>("d"), Ok('x'));
+        assert_eq!(sc.next::<String>("e"), Ok("abc".to_string()));
+        assert_eq!(sc.finish(), Ok(()));
+    }
+
+    #[test]
+    fn next_vec() {
+        let mut sc = scanner("3\n1 2\n3\n");
+        let n = sc.next::<usize>("n").unwrap();
+        assert_eq!(sc.next_vec::<u64>(n, "a"), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn next_tuple() {
+        let mut sc = scanner("2 3 -1");
+        let t = sc.next_tuple::<(usize, usize, i64)>(&["h", "w", "k"]);
+        assert_eq!(t, Ok((2, 3, -1)));
+    }
+
+    #[test]
+    fn next_chars_and_bytes() {
+        let mut sc = scanner("ab#\nxy");
+        assert_eq!(sc.next_chars("s"), Ok(vec!['a', 'b', '#']));
+        assert_eq!(sc.next_bytes("t"), Ok(b"xy".to_vec()));
+    }
+
+    #[test]
+    fn next_grid() {
+        let mut sc = scanner("2\n.#\n#.\n");
+        let h = sc.next::<usize>("h").unwrap();
+        let grid = sc.next_grid(h, "grid").unwrap();
+        assert_eq!(grid, vec![vec!['.', '#'], vec!['#', '.']]);
+    }
+
+    #[test]
+    fn next_usize1() {
+        let mut sc = scanner("1 5\n2 3");
+        assert_eq!(sc.next_usize1("u"), Ok(0));
+        assert_eq!(sc.next_usize1("v"), Ok(4));
+        assert_eq!(sc.next_vec_usize1(2, "p"), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn invalid_missing() {
+        let mut sc = scanner("1\n");
+        assert_eq!(sc.next::<usize>("a"), Ok(1));
+        let err = sc.next::<usize>("b").unwrap_err();
+        assert!(matches!(
+            err,
+            PuzzleError::Parse { line: 1, token: None, ref field, .. } if field == "b"
+        ));
+        assert_eq!(err.to_string(), "line 1: missing `b`");
+    }
+
+    #[test]
+    fn invalid_integer() {
+        let mut sc = scanner("\n-1 x");
+        let err = sc.next::<usize>("a").unwrap_err();
+        let expected = PuzzleError::Parse {
+            line: 2,
+            token: Some("-1".to_string()),
+            field: "a".to_string(),
+            expected: "positive integer".to_string(),
+        };
+        assert_eq!(err, expected);
+        assert_eq!(
+            sc.next::<i32>("b").unwrap_err().to_string(),
+            "line 2: `b` must be integer, found `x`"
+        );
+    }
+
+    #[test]
+    fn invalid_vec_item() {
+        let mut sc = scanner("1 a 3");
+        assert_eq!(
+            sc.next_vec::<u8>(3, "d").unwrap_err().to_string(),
+            "line 1: `d` must be list of positive integers, found `a`"
+        );
+    }
+
+    #[test]
+    fn invalid_vec_too_short() {
+        let mut sc = scanner("1 2");
+        assert_eq!(
+            sc.next_vec::<u8>(3, "d").unwrap_err().to_string(),
+            "line 1: missing `d`"
+        );
+    }
+
+    #[test]
+    fn invalid_tuple_item() {
+        let mut sc = scanner("2 w");
+        let t = sc.next_tuple::<(usize, usize)>(&["h", "w"]);
+        assert_eq!(
+            t.unwrap_err().to_string(),
+            "line 1: `w` must be positive integer, found `w`"
+        );
+    }
+
+    #[test]
+    fn invalid_grid_width() {
+        let mut sc = scanner(".#\n#");
+        assert_eq!(
+            sc.next_grid(2, "grid").unwrap_err().to_string(),
+            "line 2: `grid` must be row of width 2, found `#`"
+        );
+    }
+
+    #[test]
+    fn invalid_usize1_zero() {
+        let mut sc = scanner("0");
+        assert_eq!(
+            sc.next_usize1("u").unwrap_err().to_string(),
+            "line 1: `u` must be 1-based index, found `0`"
+        );
+    }
+
+    #[test]
+    fn invalid_trailing_input() {
+        let mut sc = scanner("1 2\n");
+        assert_eq!(sc.next::<usize>("a"), Ok(1));
+        let err = sc.finish().unwrap_err();
+        assert!(matches!(
+            err,
+            PuzzleError::Parse { ref expected, .. } if expected == "end of input"
+        ));
+        assert_eq!(err.to_string(), "line 1: expected end of input, found `2`");
+    }
+
+    #[test]
+    fn goto_line_locks_to_line() {
+        let mut sc = scanner("1 2\n\n3 4\n5");
+        sc.goto_line(0, "a").unwrap();
+        assert_eq!(sc.next_vec::<u32>(2, "a"), Ok(vec![1, 2]));
+        assert_eq!(
+            sc.next::<u32>("b").unwrap_err().to_string(),
+            "line 1: missing `b`"
+        );
+        sc.goto_line(2, "c").unwrap();
+        assert_eq!(sc.next::<u32>("c"), Ok(3));
+        sc.unlock_line();
+        assert_eq!(sc.next_vec::<u32>(2, "d"), Ok(vec![4, 5]));
+        assert_eq!(sc.finish(), Ok(()));
+    }
+
+    #[test]
+    fn goto_line_after_end_of_input() {
+        let mut sc = scanner("1\n");
+        sc.goto_line(0, "a").unwrap();
+        assert_eq!(sc.next::<u32>("a"), Ok(1));
+        sc.goto_line(1, "b").unwrap();
+        assert_eq!(
+            sc.next::<u32>("b").unwrap_err().to_string(),
+            "line 2: missing `b`"
+        );
+    }
+
+    #[test]
+    fn invalid_goto_line_leftover() {
+        let mut sc = scanner("1 2\n3");
+        sc.goto_line(0, "a").unwrap();
+        assert_eq!(sc.next::<u32>("a"), Ok(1));
+        assert_eq!(
+            sc.goto_line(1, "b").unwrap_err().to_string(),
+            "line 1: `b` must be at the start of line 2, found `2`"
+        );
+    }
+
+    #[test]
+    fn invalid_goto_line_passed() {
+        let mut sc = scanner("1\n2\n3");
+        assert_eq!(sc.next_vec::<u32>(2, "a"), Ok(vec![1, 2]));
+        assert_eq!(
+            sc.goto_line(0, "b").unwrap_err().to_string(),
+            "line 1: missing `b`"
+        );
+    }
+
+    type MacroOutput = (usize, Vec<Vec<i32>>, Vec<usize>);
+
+    fn read_with_macro(input: &str) -> Result<MacroOutput, PuzzleError> {
+        let mut sc = scanner(input);
+        input! {
+            from sc,
+            n: usize,
+            m: usize,
+            a: [[i32; m]; n],
+            p: [Usize1; n],
+        }
+        Ok((n, a, p))
+    }
+
+    #[test]
+    fn input_macro() {
+        let (n, a, p) = read_with_macro("2 3\n1 -2 3\n4 5 -6\n2 1\n").unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(a, vec![vec![1, -2, 3], vec![4, 5, -6]]);
+        assert_eq!(p, vec![1, 0]);
+    }
+
+    #[test]
+    fn input_macro_chars_and_bytes() {
+        let mut sc = scanner("2\nab\ncd\nxyz");
+        let result: Result<_, PuzzleError> = (|| {
+            input! {
+                from sc,
+                h: usize,
+                grid: [Chars; h],
+                mut t: Bytes,
+            }
+            t.reverse();
+            Ok((grid, t))
+        })();
+        let (grid, t) = result.unwrap();
+        assert_eq!(grid, vec![vec!['a', 'b'], vec!['c', 'd']]);
+        assert_eq!(t, b"zyx".to_vec());
+    }
+
+    #[test]
+    fn invalid_input_macro_missing() {
+        let result = read_with_macro("2 3\n1 -2 3\n4 5\n");
+        assert_eq!(result.unwrap_err().to_string(), "line 3: missing `a`");
+    }
+
+    #[test]
+    fn invalid_input_macro_integer() {
+        let result = read_with_macro("2 x\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1: `m` must be positive integer, found `x`"
+        );
+    }
+
+    #[test]
+    fn invalid_input_macro_list() {
+        let result = read_with_macro("2 3\n1 -2 3\n4 5 -6\n2 0\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 4: `p` must be list of 1-based indices, found `0`"
+        );
+    }
+}
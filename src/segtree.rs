@@ -0,0 +1,831 @@
+//! Segment trees over a monoid, with the semantics of the AtCoder Library.
+//!
+//! `SegTree<M>` supports point updates and range products in O(log n). `LazySegTree<M, F>` also
+//! applies a map from `F` to a whole range, `F` being a monoid of maps acting on `M`. Ranges are
+//! half-open like in Rust; `max_right()` and `min_left()` binary search with a monotone predicate.
+//!
+//! ```
+//! use atcoder_rust::segtree::{Min, SegTree};
+//!
+//! let mut tree = SegTree::<Min<i64>>::from(vec![5, 3, 8, 1]);
+//! assert_eq!(tree.prod(0..3), 3);
+//! tree.set(1, 9);
+//! assert_eq!(tree.prod(..3), 5);
+//! assert_eq!(tree.max_right(0, |&x| x >= 5), 3);
+//! ```
+
+use crate::modint::ModInt;
+use crate::range::to_half_open;
+use std::marker::PhantomData;
+use std::ops::{Add, BitXor, RangeBounds};
+
+/// A set with an associative operation and its identity element.
+pub trait Monoid {
+    type S: Clone;
+
+    fn identity() -> Self::S;
+    fn binary_operation(a: &Self::S, b: &Self::S) -> Self::S;
+}
+
+/// A monoid of maps acting on the values of `M`, for `LazySegTree`.
+///
+/// `mapping(f, op(x, y))` must be `op(mapping(f, x), mapping(f, y))`.
+pub trait MapMonoid<M: Monoid> {
+    type F: Clone;
+
+    fn identity_map() -> Self::F;
+    fn mapping(f: &Self::F, x: &M::S) -> M::S;
+    /// `f ∘ g`, that is `g` applied first.
+    fn composition(f: &Self::F, g: &Self::F) -> Self::F;
+}
+
+/// The integer types with their extreme values, the identities of `Min` and `Max`.
+pub trait BoundedValue: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded_value {
+    ($($t:ty),+) => {
+        $(
+            impl BoundedValue for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )+
+    };
+}
+
+impl_bounded_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Sum, for any `T` whose `Default` is zero, including `ModInt`.
+pub struct Additive<T>(PhantomData<T>);
+
+impl<T: Copy + Default + Add<Output = T>> Monoid for Additive<T> {
+    type S = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn binary_operation(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+pub struct Min<T>(PhantomData<T>);
+
+impl<T: BoundedValue> Monoid for Min<T> {
+    type S = T;
+
+    fn identity() -> T {
+        T::MAX
+    }
+
+    fn binary_operation(a: &T, b: &T) -> T {
+        *a.min(b)
+    }
+}
+
+pub struct Max<T>(PhantomData<T>);
+
+impl<T: BoundedValue> Monoid for Max<T> {
+    type S = T;
+
+    fn identity() -> T {
+        T::MIN
+    }
+
+    fn binary_operation(a: &T, b: &T) -> T {
+        *a.max(b)
+    }
+}
+
+/// Bitwise xor, for any `T` whose `Default` is zero.
+pub struct Xor<T>(PhantomData<T>);
+
+impl<T: Copy + Default + BitXor<Output = T>> Monoid for Xor<T> {
+    type S = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn binary_operation(a: &T, b: &T) -> T {
+        *a ^ *b
+    }
+}
+
+/// Affine maps `x -> a x + b` as `(a, b)` modulo `P`, the product of a range applies its maps
+/// from left to right.
+pub struct AffineComposition<const P: u32>;
+
+impl<const P: u32> Monoid for AffineComposition<P> {
+    type S = (ModInt<P>, ModInt<P>);
+
+    fn identity() -> Self::S {
+        (ModInt::one(), ModInt::zero())
+    }
+
+    fn binary_operation(f: &Self::S, g: &Self::S) -> Self::S {
+        // g(f(x)) = g.0 (f.0 x + f.1) + g.1
+        (f.0 * g.0, g.0 * f.1 + g.1)
+    }
+}
+
+/// Range `x -> a x + b` updates of `(sum, length)` pairs modulo `P`, for range sum queries.
+pub struct RangeAffineRangeSum<const P: u32>;
+
+impl<const P: u32> Monoid for RangeAffineRangeSum<P> {
+    type S = (ModInt<P>, usize);
+
+    fn identity() -> Self::S {
+        (ModInt::zero(), 0)
+    }
+
+    fn binary_operation(a: &Self::S, b: &Self::S) -> Self::S {
+        (a.0 + b.0, a.1 + b.1)
+    }
+}
+
+impl<const P: u32> MapMonoid<RangeAffineRangeSum<P>> for RangeAffineRangeSum<P> {
+    type F = (ModInt<P>, ModInt<P>);
+
+    fn identity_map() -> Self::F {
+        (ModInt::one(), ModInt::zero())
+    }
+
+    fn mapping(f: &Self::F, x: &(ModInt<P>, usize)) -> (ModInt<P>, usize) {
+        (f.0 * x.0 + f.1 * ModInt::from(x.1), x.1)
+    }
+
+    fn composition(f: &Self::F, g: &Self::F) -> Self::F {
+        (f.0 * g.0, f.0 * g.1 + f.1)
+    }
+}
+
+/// Range additions on `Min` or `Max`, where adding to all the items shifts the extremum.
+///
+/// The items must be real values: `LazySegTree::new()` fills them with the identity, `T::MAX`
+/// for `Min`, which the first addition overflows. Start from `from(vec![0; n])` instead, or use
+/// a signed or wider `T` with room above the values.
+pub struct RangeAdd<T>(PhantomData<T>);
+
+/// Range assignments on `Min` or `Max`, `None` keeps the values.
+pub struct RangeAssign<T>(PhantomData<T>);
+
+macro_rules! impl_range_maps {
+    ($($monoid:ident),+) => {
+        $(
+            impl<T: BoundedValue + Default + Add<Output = T>> MapMonoid<$monoid<T>> for RangeAdd<T> {
+                type F = T;
+
+                fn identity_map() -> T {
+                    T::default()
+                }
+
+                fn mapping(f: &T, x: &T) -> T {
+                    *f + *x
+                }
+
+                fn composition(f: &T, g: &T) -> T {
+                    *f + *g
+                }
+            }
+
+            impl<T: BoundedValue> MapMonoid<$monoid<T>> for RangeAssign<T> {
+                type F = Option<T>;
+
+                fn identity_map() -> Option<T> {
+                    None
+                }
+
+                fn mapping(f: &Option<T>, x: &T) -> T {
+                    f.unwrap_or(*x)
+                }
+
+                fn composition(f: &Option<T>, g: &Option<T>) -> Option<T> {
+                    f.or(*g)
+                }
+            }
+        )+
+    };
+}
+
+impl_range_maps!(Min, Max);
+
+/// Segment tree with point updates and range products.
+pub struct SegTree<M: Monoid> {
+    n: usize,
+    size: usize,
+    log: u32,
+    d: Vec<M::S>,
+}
+
+impl<M: Monoid> SegTree<M> {
+    /// `n` identity elements.
+    pub fn new(n: usize) -> Self {
+        vec![M::identity(); n].into()
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    pub fn set(&mut self, p: usize, x: M::S) {
+        assert!(p < self.n, "index {} out of 0..{}", p, self.n);
+        let p = p + self.size;
+        self.d[p] = x;
+        for i in 1..=self.log {
+            self.update(p >> i);
+        }
+    }
+
+    pub fn get(&self, p: usize) -> M::S {
+        assert!(p < self.n, "index {} out of 0..{}", p, self.n);
+        self.d[p + self.size].clone()
+    }
+
+    /// The product of the range, the identity if it is empty.
+    pub fn prod(&self, range: impl RangeBounds<usize>) -> M::S {
+        let (mut l, mut r) = to_half_open(range, self.n);
+        let (mut left, mut right) = (M::identity(), M::identity());
+        l += self.size;
+        r += self.size;
+        while l < r {
+            if l & 1 == 1 {
+                left = M::binary_operation(&left, &self.d[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::binary_operation(&self.d[r], &right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        M::binary_operation(&left, &right)
+    }
+
+    pub fn all_prod(&self) -> M::S {
+        self.d[1].clone()
+    }
+
+    /// The largest `r` with `pred(prod(l..r))`; `pred` must hold for the identity and be
+    /// monotone, false from some `r` on.
+    pub fn max_right(&self, l: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(l <= self.n, "index {} out of 0..={}", l, self.n);
+        assert!(
+            pred(&M::identity()),
+            "the predicate must hold for the identity"
+        );
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.size;
+        let mut acc = M::identity();
+        loop {
+            while l % 2 == 0 {
+                l >>= 1;
+            }
+            if !pred(&M::binary_operation(&acc, &self.d[l])) {
+                while l < self.size {
+                    l *= 2;
+                    let next = M::binary_operation(&acc, &self.d[l]);
+                    if pred(&next) {
+                        acc = next;
+                        l += 1;
+                    }
+                }
+                return l - self.size;
+            }
+            acc = M::binary_operation(&acc, &self.d[l]);
+            l += 1;
+            if l & l.wrapping_neg() == l {
+                return self.n;
+            }
+        }
+    }
+
+    /// The smallest `l` with `pred(prod(l..r))`; `pred` must hold for the identity and be
+    /// monotone, false from some `l` down.
+    pub fn min_left(&self, r: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(r <= self.n, "index {} out of 0..={}", r, self.n);
+        assert!(
+            pred(&M::identity()),
+            "the predicate must hold for the identity"
+        );
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.size;
+        let mut acc = M::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r % 2 == 1 {
+                r >>= 1;
+            }
+            if !pred(&M::binary_operation(&self.d[r], &acc)) {
+                while r < self.size {
+                    r = 2 * r + 1;
+                    let next = M::binary_operation(&self.d[r], &acc);
+                    if pred(&next) {
+                        acc = next;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.size;
+            }
+            acc = M::binary_operation(&self.d[r], &acc);
+            if r & r.wrapping_neg() == r {
+                return 0;
+            }
+        }
+    }
+
+    fn update(&mut self, k: usize) {
+        self.d[k] = M::binary_operation(&self.d[2 * k], &self.d[2 * k + 1]);
+    }
+}
+
+impl<M: Monoid> From<Vec<M::S>> for SegTree<M> {
+    /// Builds the tree in O(n).
+    fn from(values: Vec<M::S>) -> Self {
+        let n = values.len();
+        let size = n.next_power_of_two();
+        let log = size.trailing_zeros();
+        let mut d = vec![M::identity(); 2 * size];
+        for (i, x) in values.into_iter().enumerate() {
+            d[size + i] = x;
+        }
+        let mut tree = SegTree { n, size, log, d };
+        for k in (1..size).rev() {
+            tree.update(k);
+        }
+        tree
+    }
+}
+
+/// Segment tree with range products and range updates by the maps of `F`.
+pub struct LazySegTree<M: Monoid, F: MapMonoid<M>> {
+    n: usize,
+    size: usize,
+    log: u32,
+    d: Vec<M::S>,
+    lazy: Vec<F::F>,
+}
+
+impl<M: Monoid, F: MapMonoid<M>> LazySegTree<M, F> {
+    /// `n` identity elements, not valid items for maps like `RangeAdd`.
+    pub fn new(n: usize) -> Self {
+        vec![M::identity(); n].into()
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    pub fn set(&mut self, p: usize, x: M::S) {
+        assert!(p < self.n, "index {} out of 0..{}", p, self.n);
+        let p = p + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(p >> i);
+        }
+        self.d[p] = x;
+        for i in 1..=self.log {
+            self.update(p >> i);
+        }
+    }
+
+    pub fn get(&mut self, p: usize) -> M::S {
+        assert!(p < self.n, "index {} out of 0..{}", p, self.n);
+        let p = p + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(p >> i);
+        }
+        self.d[p].clone()
+    }
+
+    /// The product of the range, the identity if it is empty.
+    pub fn prod(&mut self, range: impl RangeBounds<usize>) -> M::S {
+        let (l, r) = to_half_open(range, self.n);
+        if l == r {
+            return M::identity();
+        }
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        self.push_boundaries(l, r);
+        let (mut left, mut right) = (M::identity(), M::identity());
+        while l < r {
+            if l & 1 == 1 {
+                left = M::binary_operation(&left, &self.d[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::binary_operation(&self.d[r], &right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        M::binary_operation(&left, &right)
+    }
+
+    pub fn all_prod(&self) -> M::S {
+        self.d[1].clone()
+    }
+
+    /// Applies `f` to the item `p`.
+    pub fn apply(&mut self, p: usize, f: F::F) {
+        assert!(p < self.n, "index {} out of 0..{}", p, self.n);
+        let p = p + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(p >> i);
+        }
+        self.d[p] = F::mapping(&f, &self.d[p]);
+        for i in 1..=self.log {
+            self.update(p >> i);
+        }
+    }
+
+    /// Applies `f` to every item of the range.
+    pub fn apply_range(&mut self, range: impl RangeBounds<usize>, f: F::F) {
+        let (l, r) = to_half_open(range, self.n);
+        if l == r {
+            return;
+        }
+        let (l, r) = (l + self.size, r + self.size);
+        self.push_boundaries(l, r);
+        let (mut l2, mut r2) = (l, r);
+        while l2 < r2 {
+            if l2 & 1 == 1 {
+                self.all_apply(l2, &f);
+                l2 += 1;
+            }
+            if r2 & 1 == 1 {
+                r2 -= 1;
+                self.all_apply(r2, &f);
+            }
+            l2 >>= 1;
+            r2 >>= 1;
+        }
+        for i in 1..=self.log {
+            if (l >> i) << i != l {
+                self.update(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.update((r - 1) >> i);
+            }
+        }
+    }
+
+    /// The largest `r` with `pred(prod(l..r))`, see `SegTree::max_right()`.
+    pub fn max_right(&mut self, l: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(l <= self.n, "index {} out of 0..={}", l, self.n);
+        assert!(
+            pred(&M::identity()),
+            "the predicate must hold for the identity"
+        );
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.size;
+        for i in (1..=self.log).rev() {
+            self.push(l >> i);
+        }
+        let mut acc = M::identity();
+        loop {
+            while l % 2 == 0 {
+                l >>= 1;
+            }
+            if !pred(&M::binary_operation(&acc, &self.d[l])) {
+                while l < self.size {
+                    self.push(l);
+                    l *= 2;
+                    let next = M::binary_operation(&acc, &self.d[l]);
+                    if pred(&next) {
+                        acc = next;
+                        l += 1;
+                    }
+                }
+                return l - self.size;
+            }
+            acc = M::binary_operation(&acc, &self.d[l]);
+            l += 1;
+            if l & l.wrapping_neg() == l {
+                return self.n;
+            }
+        }
+    }
+
+    /// The smallest `l` with `pred(prod(l..r))`, see `SegTree::min_left()`.
+    pub fn min_left(&mut self, r: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(r <= self.n, "index {} out of 0..={}", r, self.n);
+        assert!(
+            pred(&M::identity()),
+            "the predicate must hold for the identity"
+        );
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.size;
+        for i in (1..=self.log).rev() {
+            self.push((r - 1) >> i);
+        }
+        let mut acc = M::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r % 2 == 1 {
+                r >>= 1;
+            }
+            if !pred(&M::binary_operation(&self.d[r], &acc)) {
+                while r < self.size {
+                    self.push(r);
+                    r = 2 * r + 1;
+                    let next = M::binary_operation(&self.d[r], &acc);
+                    if pred(&next) {
+                        acc = next;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.size;
+            }
+            acc = M::binary_operation(&self.d[r], &acc);
+            if r & r.wrapping_neg() == r {
+                return 0;
+            }
+        }
+    }
+
+    /// Pushes the pending maps down to the nodes of `[l, r)`, given as leaf indexes.
+    fn push_boundaries(&mut self, l: usize, r: usize) {
+        for i in (1..=self.log).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+    }
+
+    fn update(&mut self, k: usize) {
+        self.d[k] = M::binary_operation(&self.d[2 * k], &self.d[2 * k + 1]);
+    }
+
+    fn all_apply(&mut self, k: usize, f: &F::F) {
+        self.d[k] = F::mapping(f, &self.d[k]);
+        if k < self.size {
+            self.lazy[k] = F::composition(f, &self.lazy[k]);
+        }
+    }
+
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.lazy[k], F::identity_map());
+        self.all_apply(2 * k, &f);
+        self.all_apply(2 * k + 1, &f);
+    }
+}
+
+impl<M: Monoid, F: MapMonoid<M>> From<Vec<M::S>> for LazySegTree<M, F> {
+    /// Builds the tree in O(n).
+    fn from(values: Vec<M::S>) -> Self {
+        let n = values.len();
+        let size = n.next_power_of_two();
+        let log = size.trailing_zeros();
+        let mut d = vec![M::identity(); 2 * size];
+        for (i, x) in values.into_iter().enumerate() {
+            d[size + i] = x;
+        }
+        let lazy = vec![F::identity_map(); size];
+        let mut tree = LazySegTree {
+            n,
+            size,
+            log,
+            d,
+            lazy,
+        };
+        for k in (1..size).rev() {
+            tree.update(k);
+        }
+        tree
+    }
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modint::{Mint998, MODULUS_998};
+    use crate::rng::{random_range, XorShift64};
+
+    #[test]
+    fn segtree_sum_vs_naive() {
+        let mut rng = XorShift64::new(23);
+        for n in [0, 1, 2, 7, 8, 33] {
+            let mut naive = (0..n).map(|_| rng.range_i64(0, 100)).collect::<Vec<_>>();
+            let mut tree = SegTree::<Additive<i64>>::from(naive.clone());
+            assert_eq!(tree.len(), n);
+            for _ in 0..300 {
+                if n > 0 && rng.below(3) == 0 {
+                    let p = rng.below(n as u64) as usize;
+                    naive[p] = rng.range_i64(0, 100);
+                    tree.set(p, naive[p]);
+                    assert_eq!(tree.get(p), naive[p]);
+                }
+                let (l, r) = random_range(&mut rng, n);
+                assert_eq!(tree.prod(l..r), naive[l..r].iter().sum::<i64>());
+                assert_eq!(tree.all_prod(), naive.iter().sum::<i64>());
+                // prefix sums are monotone, so `sum <= k` is a valid predicate
+                let k = rng.range_i64(0, 500);
+                let expected_r = (l..=n)
+                    .take_while(|&r| naive[l..r].iter().sum::<i64>() <= k)
+                    .last()
+                    .unwrap();
+                assert_eq!(tree.max_right(l, |&s| s <= k), expected_r);
+                let expected_l = (0..=r)
+                    .rev()
+                    .take_while(|&l| naive[l..r].iter().sum::<i64>() <= k)
+                    .last()
+                    .unwrap();
+                assert_eq!(tree.min_left(r, |&s| s <= k), expected_l);
+            }
+        }
+    }
+
+    #[test]
+    fn segtree_min_max_xor_vs_naive() {
+        let mut rng = XorShift64::new(24);
+        let n = 20;
+        let mut naive = (0..n).map(|_| rng.next_u64() as i32).collect::<Vec<_>>();
+        let mut min = SegTree::<Min<i32>>::from(naive.clone());
+        let mut max = SegTree::<Max<i32>>::from(naive.clone());
+        let mut xor = SegTree::<Xor<u32>>::new(n);
+        for (p, &x) in naive.iter().enumerate() {
+            xor.set(p, x as u32);
+        }
+        for _ in 0..300 {
+            let p = rng.below(n as u64) as usize;
+            naive[p] = rng.next_u64() as i32;
+            min.set(p, naive[p]);
+            max.set(p, naive[p]);
+            xor.set(p, naive[p] as u32);
+            let (l, r) = random_range(&mut rng, n);
+            let range = &naive[l..r];
+            assert_eq!(
+                min.prod(l..r),
+                range.iter().copied().min().unwrap_or(i32::MAX)
+            );
+            assert_eq!(
+                max.prod(l..r),
+                range.iter().copied().max().unwrap_or(i32::MIN)
+            );
+            assert_eq!(
+                xor.prod(l..r),
+                range.iter().fold(0, |acc, &x| acc ^ x as u32)
+            );
+        }
+        assert_eq!(min.prod(..), *naive.iter().min().unwrap());
+        assert_eq!(max.prod(3..=5), *naive[3..=5].iter().max().unwrap());
+    }
+
+    #[test]
+    fn segtree_affine_composition() {
+        let mut rng = XorShift64::new(25);
+        let n = 30;
+        let random_map =
+            |rng: &mut XorShift64| (Mint998::new(rng.next_u64()), Mint998::new(rng.next_u64()));
+        let mut naive = (0..n).map(|_| random_map(&mut rng)).collect::<Vec<_>>();
+        let mut tree = SegTree::<AffineComposition<MODULUS_998>>::from(naive.clone());
+        for _ in 0..200 {
+            let p = rng.below(n as u64) as usize;
+            naive[p] = random_map(&mut rng);
+            tree.set(p, naive[p]);
+            let (l, r) = random_range(&mut rng, n);
+            let x = Mint998::new(rng.next_u64());
+            let expected = naive[l..r].iter().fold(x, |x, f| f.0 * x + f.1);
+            let f = tree.prod(l..r);
+            assert_eq!(f.0 * x + f.1, expected);
+        }
+    }
+
+    #[test]
+    fn lazy_segtree_affine_sum_vs_naive() {
+        type Tree = LazySegTree<RangeAffineRangeSum<MODULUS_998>, RangeAffineRangeSum<MODULUS_998>>;
+        let mut rng = XorShift64::new(26);
+        for n in [1, 5, 16, 37] {
+            let mut naive = (0..n)
+                .map(|_| Mint998::new(rng.next_u64()))
+                .collect::<Vec<_>>();
+            let mut tree = Tree::from(naive.iter().map(|&x| (x, 1)).collect::<Vec<_>>());
+            for _ in 0..300 {
+                let (l, r) = random_range(&mut rng, n);
+                match rng.below(4) {
+                    0 => {
+                        let f = (Mint998::new(rng.next_u64()), Mint998::new(rng.next_u64()));
+                        tree.apply_range(l..r, f);
+                        naive[l..r].iter_mut().for_each(|x| *x = f.0 * *x + f.1);
+                    }
+                    1 => {
+                        let p = rng.below(n as u64) as usize;
+                        naive[p] = Mint998::new(rng.next_u64());
+                        tree.set(p, (naive[p], 1));
+                    }
+                    2 => {
+                        let p = rng.below(n as u64) as usize;
+                        let f = (Mint998::new(3), Mint998::new(1));
+                        tree.apply(p, f);
+                        naive[p] = naive[p] * f.0 + f.1;
+                    }
+                    _ => {
+                        let p = rng.below(n as u64) as usize;
+                        assert_eq!(tree.get(p), (naive[p], 1));
+                    }
+                }
+                assert_eq!(tree.prod(l..r), (naive[l..r].iter().sum(), r - l));
+                assert_eq!(tree.all_prod().0, naive.iter().sum());
+            }
+        }
+    }
+
+    #[test]
+    fn lazy_segtree_add_min_vs_naive() {
+        let mut rng = XorShift64::new(27);
+        let n = 25;
+        let mut naive = (0..n).map(|_| rng.range_i64(-100, 100)).collect::<Vec<_>>();
+        let mut tree = LazySegTree::<Min<i64>, RangeAdd<i64>>::from(naive.clone());
+        for _ in 0..500 {
+            let (l, r) = random_range(&mut rng, n);
+            let v = rng.range_i64(-50, 50);
+            tree.apply_range(l..r, v);
+            naive[l..r].iter_mut().for_each(|x| *x += v);
+            let (l, r) = random_range(&mut rng, n);
+            assert_eq!(
+                tree.prod(l..r),
+                naive[l..r].iter().copied().min().unwrap_or(i64::MAX)
+            );
+            // the running minimum only decreases, so `min >= k` is a valid predicate
+            let k = rng.range_i64(-200, 100);
+            let expected_r = (l..=n)
+                .take_while(|&r| naive[l..r].iter().all(|&x| x >= k))
+                .last()
+                .unwrap();
+            assert_eq!(tree.max_right(l, |&m| m >= k), expected_r);
+            let expected_l = (0..=r)
+                .rev()
+                .take_while(|&l| naive[l..r].iter().all(|&x| x >= k))
+                .last()
+                .unwrap();
+            assert_eq!(tree.min_left(r, |&m| m >= k), expected_l);
+        }
+    }
+
+    #[test]
+    fn lazy_segtree_add_to_identity_values() {
+        let mut tree = LazySegTree::<Max<u64>, RangeAdd<u64>>::from(vec![0; 3]);
+        tree.apply_range(.., 5);
+        assert_eq!(tree.all_prod(), 5);
+        tree.apply_range(1..2, 2);
+        assert_eq!(tree.prod(..), 7);
+        assert_eq!(tree.get(2), 5);
+        let mut tree = LazySegTree::<Min<i32>, RangeAdd<i32>>::from(vec![i32::MAX, 0]);
+        tree.apply_range(.., -1);
+        assert_eq!(tree.get(0), i32::MAX - 1);
+        assert_eq!(tree.all_prod(), -1);
+    }
+
+    #[test]
+    fn lazy_segtree_assign_max_vs_naive() {
+        let mut rng = XorShift64::new(28);
+        let n = 19;
+        let mut naive = vec![0u32; n];
+        let mut tree = LazySegTree::<Max<u32>, RangeAssign<u32>>::new(n);
+        for p in 0..n {
+            tree.set(p, 0);
+        }
+        for _ in 0..500 {
+            let (l, r) = random_range(&mut rng, n);
+            let v = rng.below(1000) as u32;
+            tree.apply_range(l..r, Some(v));
+            naive[l..r].iter_mut().for_each(|x| *x = v);
+            let (l, r) = random_range(&mut rng, n);
+            assert_eq!(
+                tree.prod(l..r),
+                naive[l..r].iter().copied().max().unwrap_or(0)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of")]
+    fn invalid_range_beyond_end() {
+        SegTree::<Additive<i64>>::new(3).prod(1..4);
+    }
+}
@@ -0,0 +1,392 @@
+//! Sample verifier: runs puzzles on their example cases in `./input/`, and compares the results
+//! with the expected outputs.
+//!
+//! Example case `N` of a puzzle is the input file `PUZZLE_ID_N.txt` with the expected output in
+//! `PUZZLE_ID_N.out`, so adding a sample needs no new test function.
+
+use crate::memory;
+use crate::{case_path, open_file, DynPuzzle, PuzzleError, INPUT_DIR};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::panic;
+use std::time;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Verdict {
+    Accepted,
+    WrongAnswer,
+    /// The puzzle returned an error or panicked.
+    RuntimeError,
+    TimeLimitExceeded,
+    MemoryLimitExceeded,
+    /// There is no expected output file, the case was only run.
+    Unjudged,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Verdict::Accepted | Verdict::Unjudged)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Verdict::Accepted => "AC",
+            Verdict::WrongAnswer => "WA",
+            Verdict::RuntimeError => "RE",
+            Verdict::TimeLimitExceeded => "TLE",
+            Verdict::MemoryLimitExceeded => "MLE",
+            Verdict::Unjudged => "--",
+        };
+        f.pad(code)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CaseResult {
+    pub puzzle_id: &'static str,
+    pub case: String,
+    pub verdict: Verdict,
+    pub elapsed: time::Duration,
+    /// Peak heap usage in bytes, see `memory::measure()`.
+    pub peak_memory: usize,
+    /// The error for `RE`, or the actual output for `WA`.
+    pub detail: String,
+}
+
+/// Compares the outputs line by line, ignoring trailing whitespace and trailing empty lines.
+pub fn outputs_match(actual: &str, expected: &str) -> bool {
+    fn lines(s: &str) -> Vec<&str> {
+        let mut lines = s.lines().map(str::trim_end).collect::<Vec<_>>();
+        while lines.last() == Some(&"") {
+            lines.pop();
+        }
+        lines
+    }
+    lines(actual) == lines(expected)
+}
+
+/// Returns `TLE` or `MLE` if the local measurements exceed the limits of the puzzle.
+///
+/// The elapsed time is multiplied by `safety_factor`, to account for a judge slower (> 1.0)
+/// or faster (< 1.0) than the local machine.
+pub fn check_limits(
+    puzzle: &dyn DynPuzzle,
+    elapsed: time::Duration,
+    peak_memory: Option<usize>,
+    safety_factor: f64,
+) -> Option<Verdict> {
+    if elapsed.mul_f64(safety_factor) > puzzle.time_limit() {
+        Some(Verdict::TimeLimitExceeded)
+    } else if peak_memory.is_some_and(|memory| memory > puzzle.memory_limit()) {
+        Some(Verdict::MemoryLimitExceeded)
+    } else {
+        None
+    }
+}
+
+/// Runs the puzzle on a single case, and compares the result with `expected`, if given.
+///
+/// The limits are checked only with a `safety_factor`, see `check_limits()`.
+pub fn judge(
+    puzzle: &dyn DynPuzzle,
+    case: &str,
+    reader: &mut dyn BufRead,
+    expected: Option<&str>,
+    safety_factor: Option<f64>,
+) -> CaseResult {
+    let now = time::Instant::now();
+    let (result, peak_memory) =
+        memory::measure(|| panic::catch_unwind(panic::AssertUnwindSafe(|| puzzle.execute(reader))));
+    let elapsed = now.elapsed();
+    let over_limit =
+        safety_factor.and_then(|f| check_limits(puzzle, elapsed, Some(peak_memory), f));
+    let (verdict, detail) = match (result, over_limit) {
+        (Err(_), _) => (Verdict::RuntimeError, "panicked".to_string()),
+        (Ok(Err(err)), _) => (Verdict::RuntimeError, err.to_string()),
+        (Ok(Ok(_)), Some(verdict)) => (verdict, String::new()),
+        (Ok(Ok(actual)), None) => match expected {
+            None => (Verdict::Unjudged, String::new()),
+            Some(expected) if outputs_match(&actual, expected) => {
+                (Verdict::Accepted, String::new())
+            }
+            Some(_) => (Verdict::WrongAnswer, actual),
+        },
+    };
+    CaseResult {
+        puzzle_id: puzzle.id(),
+        case: case.to_string(),
+        verdict,
+        elapsed,
+        peak_memory,
+        detail,
+    }
+}
+
+/// Lists the example case ids of the puzzle, i.e. the `N`s of the `PUZZLE_ID_N.txt` files.
+pub fn find_cases(puzzle_id: &str) -> Result<Vec<String>, PuzzleError> {
+    let io_error = |source| PuzzleError::Io {
+        path: INPUT_DIR.to_string(),
+        source,
+    };
+    let prefix = format!("{}_", puzzle_id);
+    let mut cases = Vec::new();
+    for entry in fs::read_dir(INPUT_DIR).map_err(io_error)? {
+        let file_name = entry.map_err(io_error)?.file_name();
+        let Some(case) = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix(&prefix))
+            .and_then(|name| name.strip_suffix(".txt"))
+        else {
+            continue;
+        };
+        if !case.is_empty() && !case.contains('_') {
+            cases.push(case.to_string());
+        }
+    }
+    // numeric case ids in numeric order
+    cases.sort_by(|a, b| (a.len(), a).cmp(&(b.len(), b)));
+    Ok(cases)
+}
+
+/// Reads the expected output of the case, or returns `None` if there is no `.out` file.
+pub fn read_expected(puzzle_id: &str, case: &str) -> Result<Option<String>, PuzzleError> {
+    let path = case_path(puzzle_id, case, "out");
+    match fs::read_to_string(&path) {
+        Ok(expected) => Ok(Some(expected)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(PuzzleError::Io { path, source }),
+    }
+}
+
+/// Judges all example cases of the puzzles, see `judge()`.
+pub fn verify(
+    puzzles: &[&dyn DynPuzzle],
+    safety_factor: Option<f64>,
+) -> Result<Vec<CaseResult>, PuzzleError> {
+    let mut results = Vec::new();
+    for &puzzle in puzzles {
+        for case in find_cases(puzzle.id())? {
+            let expected = read_expected(puzzle.id(), &case)?;
+            let mut reader = open_file(puzzle.id(), &case)?;
+            let result = judge(
+                puzzle,
+                &case,
+                &mut reader,
+                expected.as_deref(),
+                safety_factor,
+            );
+            results.push(result);
+        }
+    }
+    Ok(results)
+}
+
+/// Formats the results as a table, followed by a summary line.
+pub fn format_table(results: &[CaseResult]) -> String {
+    let mut table = format!(
+        "{:<12}{:<6}{:<9}{:>8}{:>12}  detail\n",
+        "puzzle", "case", "verdict", "time", "memory"
+    );
+    for result in results {
+        let detail = result.detail.lines().next().unwrap_or_default();
+        let row = format!(
+            "{:<12}{:<6}{:<9}{:>5} ms{:>12}  {}",
+            result.puzzle_id,
+            result.case,
+            result.verdict,
+            result.elapsed.as_millis(),
+            memory::format_memory(result.peak_memory),
+            detail
+        );
+        table += row.trim_end();
+        table.push('\n');
+    }
+    let failed = results.iter().filter(|r| r.verdict.is_failure()).count();
+    let accepted = results
+        .iter()
+        .filter(|r| r.verdict == Verdict::Accepted)
+        .count();
+    table += &format!(
+        "{} cases: {} AC, {} failed, {} unjudged\n",
+        results.len(),
+        accepted,
+        failed,
+        results.len() - accepted - failed
+    );
+    table
+}
+
+// ------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::Scanner;
+    use crate::{puzzles, Puzzle};
+
+    /// Allocates a buffer of exactly `n` bytes, for the memory measurement.
+    struct Allocate;
+
+    impl Puzzle for Allocate {
+        type Input = usize;
+        type Output = usize;
+
+        fn id(&self) -> &'static str {
+            "allocate"
+        }
+
+        fn title(&self) -> &'static str {
+            "Allocate n bytes"
+        }
+
+        fn parse<R: BufRead>(&self, sc: &mut Scanner<R>) -> Result<usize, PuzzleError> {
+            sc.next("n")
+        }
+
+        fn solve(&self, &n: &usize) -> Result<usize, PuzzleError> {
+            let buffer = vec![1u8; n];
+            Ok(buffer.iter().map(|&x| x as usize).sum())
+        }
+    }
+
+    fn judge_str(input: &str, expected: Option<&str>) -> CaseResult {
+        let puzzle = puzzles::find("practice_a").unwrap();
+        judge(puzzle, "x", &mut input.as_bytes(), expected, Some(1.0))
+    }
+
+    #[test]
+    fn outputs_match_ignores_trailing_whitespace() {
+        assert!(outputs_match("6 test", "6 test\n"));
+        assert!(outputs_match("1\n2  \n", "1\r\n2\n\n"));
+        assert!(!outputs_match("1\n2", "1 2"));
+        assert!(!outputs_match("6 test", "6  test"));
+    }
+
+    #[test]
+    fn judge_accepted() {
+        let result = judge_str("1\n2 3\ntest", Some("6 test\n"));
+        assert_eq!(result.verdict, Verdict::Accepted);
+        assert_eq!(result.puzzle_id, "practice_a");
+        assert_eq!(result.case, "x");
+    }
+
+    #[test]
+    fn judge_records_peak_memory() {
+        let n = 1 << 20;
+        let text = n.to_string();
+        let result = judge(&Allocate, "x", &mut text.as_bytes(), Some(&text), None);
+        assert_eq!(result.verdict, Verdict::Accepted);
+        // other test threads may allocate meanwhile, so only the lower bound is exact
+        assert!(result.peak_memory >= n);
+    }
+
+    #[test]
+    fn judge_wrong_answer() {
+        let result = judge_str("1\n2 3\ntest", Some("7 test\n"));
+        assert_eq!(result.verdict, Verdict::WrongAnswer);
+        assert_eq!(result.detail, "6 test");
+    }
+
+    #[test]
+    fn judge_runtime_error() {
+        let result = judge_str("1\n2 3\n", Some("6 test\n"));
+        assert_eq!(result.verdict, Verdict::RuntimeError);
+        assert_eq!(result.detail, "line 3: missing `s`");
+    }
+
+    #[test]
+    fn judge_time_limit_exceeded() {
+        let puzzle = puzzles::find("practice_a").unwrap();
+        let input = &mut "1\n2 3\ntest".as_bytes();
+        let result = judge(puzzle, "x", input, Some("6 test"), Some(1e12));
+        assert_eq!(result.verdict, Verdict::TimeLimitExceeded);
+    }
+
+    #[test]
+    fn check_limits_scaled_time() {
+        let puzzle = puzzles::find("abc042_d").unwrap();
+        let elapsed = time::Duration::from_millis(1500);
+        assert_eq!(check_limits(puzzle, elapsed, None, 1.0), None);
+        let verdict = check_limits(puzzle, elapsed, None, 1.5);
+        assert_eq!(verdict, Some(Verdict::TimeLimitExceeded));
+    }
+
+    #[test]
+    fn check_limits_memory() {
+        let puzzle = puzzles::find("abc042_d").unwrap();
+        let elapsed = time::Duration::ZERO;
+        let limit = puzzle.memory_limit();
+        assert_eq!(check_limits(puzzle, elapsed, Some(limit), 1.0), None);
+        let verdict = check_limits(puzzle, elapsed, Some(limit + 1), 1.0);
+        assert_eq!(verdict, Some(Verdict::MemoryLimitExceeded));
+    }
+
+    #[test]
+    fn judge_unjudged() {
+        let result = judge_str("1\n2 3\ntest", None);
+        assert_eq!(result.verdict, Verdict::Unjudged);
+        assert!(!result.verdict.is_failure());
+    }
+
+    #[test]
+    fn find_cases_sorted() {
+        assert_eq!(find_cases("abc042_d").unwrap(), ["1", "4"]);
+        assert!(find_cases("abc999_z").unwrap().is_empty());
+    }
+
+    #[test]
+    fn read_expected_existing() {
+        let expected = read_expected("abc042_d", "1").unwrap();
+        assert_eq!(expected.as_deref().map(str::trim_end), Some("2"));
+        assert_eq!(read_expected("abc042_d", "0").unwrap(), None);
+    }
+
+    #[test]
+    fn verify_all_cases_of_puzzle() {
+        let results = verify(&[puzzles::find("abc042_b").unwrap()], None).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].verdict, Verdict::Accepted);
+    }
+
+    /// Runs a case with no time limit, asserting `AC`, or just a successful run without `.out`.
+    fn check_case(puzzle_id: &str, case: &str) {
+        let puzzle = puzzles::find(puzzle_id)
+            .unwrap_or_else(|| panic!("no puzzle for input file of `{}`", puzzle_id));
+        let expected = read_expected(puzzle_id, case).unwrap();
+        let mut reader = open_file(puzzle_id, case).unwrap();
+        let result = judge(puzzle, case, &mut reader, expected.as_deref(), None);
+        assert!(
+            !result.verdict.is_failure(),
+            "{} case {}: {} {}",
+            puzzle_id,
+            case,
+            result.verdict,
+            result.detail
+        );
+    }
+
+    /// One generated test per `input/PUZZLE_ID_N.txt` file, see `build.rs`.
+    mod samples {
+        use super::check_case;
+
+        include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+    }
+
+    #[test]
+    fn format_table_summary() {
+        let results = [
+            judge_str("1\n2 3\ntest", Some("6 test")),
+            judge_str("1\n2 3\ntest", Some("5 test")),
+        ];
+        let table = format_table(&results);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("practice_a  x     AC "));
+        assert!(lines[2].starts_with("practice_a  x     WA "));
+        assert!(lines[2].ends_with(" KiB  6 test"));
+        assert_eq!(lines[3], "2 cases: 1 AC, 1 failed, 0 unjudged");
+    }
+}
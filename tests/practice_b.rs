@@ -0,0 +1,34 @@
+//! Runs the interactive `practice_b` binary against the local judge.
+
+use atcoder_rust::interactive::{spawn, SortingJudge};
+use atcoder_rust::rng::XorShift64;
+use atcoder_rust::verify::Verdict;
+use std::path::Path;
+
+const PROGRAM: &str = env!("CARGO_BIN_EXE_practice_b");
+
+#[test]
+fn bubble_sort_within_large_query_limit() {
+    let mut rng = XorShift64::new(1);
+    let mut judge = SortingJudge::random(26, 1000, &mut rng);
+    let report = spawn(Path::new(PROGRAM), &mut judge).unwrap();
+    assert_eq!(report.verdict, Verdict::Accepted, "{}", report.detail);
+    assert_eq!(report.queries, 26 * 25);
+}
+
+#[test]
+fn merge_insertion_within_small_query_limit() {
+    let mut rng = XorShift64::new(2);
+    let mut judge = SortingJudge::random(26, 100, &mut rng);
+    let report = spawn(Path::new(PROGRAM), &mut judge).unwrap();
+    assert_eq!(report.verdict, Verdict::Accepted, "{}", report.detail);
+    assert!(report.queries <= 91);
+}
+
+#[test]
+fn five_items_within_seven_queries() {
+    let mut judge = SortingJudge::new("EDCBA", 7);
+    let report = spawn(Path::new(PROGRAM), &mut judge).unwrap();
+    assert_eq!(report.verdict, Verdict::Accepted, "{}", report.detail);
+    assert!(report.queries <= 7);
+}